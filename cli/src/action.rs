@@ -9,6 +9,10 @@ const BASE: u32 = 16;
 pub enum Action {
     /// Abort the game.
     Abort,
+    /// Pause the game.
+    Pause,
+    /// Resume the paused game.
+    Resume,
     /// An actual game engine action.
     Action(rustymines::Action),
 }
@@ -20,12 +24,30 @@ impl FromStr for Action {
     fn from_str(string: &str) -> Result<Self, Self::Err> {
         match string {
             "exit" | "quit" | "q" => Ok(Self::Abort),
+            "pause" | "p" => Ok(Self::Pause),
+            "resume" | "r" => Ok(Self::Resume),
             "!!" => Ok(Self::Action(rustymines::Action::VisitAllNonFlaggedFields)),
             string => {
                 if string.starts_with('!') {
                     parse_coordinate(&string.replace('!', ""))
                         .map(rustymines::Action::ToggleFlag)
                         .map(Self::Action)
+                } else if string.starts_with('?') {
+                    parse_coordinate(&string.replace('?', ""))
+                        .map(rustymines::Action::ToggleMark)
+                        .map(Self::Action)
+                } else if string.starts_with('>') {
+                    parse_coordinate(&string.replace('>', ""))
+                        .map(rustymines::Action::RaiseFlag)
+                        .map(Self::Action)
+                } else if string.starts_with('<') {
+                    parse_coordinate(&string.replace('<', ""))
+                        .map(rustymines::Action::LowerFlag)
+                        .map(Self::Action)
+                } else if string.starts_with('+') {
+                    parse_coordinate(&string.replace('+', ""))
+                        .map(rustymines::Action::Chord)
+                        .map(Self::Action)
                 } else {
                     parse_coordinate(string)
                         .map(rustymines::Action::Visit)
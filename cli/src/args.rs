@@ -1,32 +1,91 @@
 use std::num::NonZero;
+use std::path::PathBuf;
+use std::time::Duration;
 
 use clap::Parser;
-use rustymines::{Error, Game};
+use rustymines::{Config, Difficulty, Error, Game};
 
-const DEFAULT_SIZE: NonZero<usize> = NonZero::new(8).expect("Default size should be non-zero.");
+use crate::neighborhood::Neighborhood;
+use crate::preset::Preset;
+use crate::topology::Topology;
 
 /// Command line arguments.
 #[derive(Parser)]
 #[clap(about, author, version)]
 pub struct Args {
-    /// The width of the game board.
-    #[clap(short, long, name = "width", value_parser, default_value_t = DEFAULT_SIZE)]
-    pub width: NonZero<usize>,
-    /// The height of the game board.
-    #[clap(short = 'H', long, name = "height", value_parser, default_value_t = DEFAULT_SIZE)]
-    pub height: NonZero<usize>,
-    /// The amount of mines on the game board.
-    #[clap(short, long, name = "mines", value_parser, default_value_t = 10)]
-    pub mines: u8,
+    /// The difficulty preset, determining the board size and amount of mines.
+    #[clap(short, long, name = "preset", value_enum, default_value_t)]
+    pub preset: Preset,
+    /// The width of the game board, overriding the preset.
+    #[clap(short, long, name = "width", value_parser)]
+    pub width: Option<NonZero<usize>>,
+    /// The height of the game board, overriding the preset.
+    #[clap(short = 'H', long, name = "height", value_parser)]
+    pub height: Option<NonZero<usize>>,
+    /// The amount of mines on the game board, overriding the preset.
+    #[clap(short, long, name = "mines", value_parser)]
+    pub mines: Option<usize>,
+    /// The maximum amount of mines a single field can hold.
+    #[clap(short = 'M', long, name = "mines-per-field", value_parser)]
+    pub mines_per_field: Option<NonZero<u8>>,
     /// The amount of duds among the mines.
     #[clap(short, long, name = "duds", value_parser, default_value_t = 0)]
-    pub duds: u8,
+    pub duds: usize,
+    /// The topology of the game board.
+    #[clap(short = 'T', long, name = "topology", value_enum, default_value_t)]
+    pub topology: Topology,
+    /// The neighborhood of the fields, determining which fields are adjacent.
+    #[clap(short = 'N', long, name = "neighborhood", value_enum, default_value_t)]
+    pub neighborhood: Neighborhood,
+    /// The radius of the radius neighborhood.
+    #[clap(short = 'R', long, name = "radius", value_parser, default_value = "2")]
+    pub radius: NonZero<u8>,
+    /// The seed for placing mines and duds.
+    #[clap(short, long, name = "seed", value_parser)]
+    pub seed: Option<u64>,
+    /// Generate a board that can be solved without guessing, trying at most this many layouts.
+    #[clap(short, long, name = "attempts", value_parser)]
+    pub no_guess: Option<NonZero<usize>>,
+    /// The time limit of the game in seconds.
+    #[clap(short, long, name = "seconds", value_parser)]
+    pub time_limit: Option<NonZero<u64>>,
+    /// The maximum amount of moves.
+    #[clap(short = 'l', long, name = "moves", value_parser)]
+    pub move_limit: Option<NonZero<usize>>,
+    /// The amount of lives, i.e. how many mines may be stepped onto before losing the game.
+    #[clap(short = 'L', long, name = "lives", value_parser)]
+    pub lives: Option<NonZero<usize>>,
+    /// Write a RAW video of the game to the given file when the game is over.
+    #[clap(short, long, name = "file", value_parser)]
+    pub rawvf: Option<PathBuf>,
 }
 
 impl TryFrom<Args> for Game {
     type Error = Error;
 
     fn try_from(args: Args) -> Result<Self, Self::Error> {
-        Self::new(args.width, args.height, args.mines, args.duds)
+        let difficulty = Difficulty::from(args.preset);
+        let config = Config::new(
+            args.width.unwrap_or_else(|| difficulty.width()),
+            args.height.unwrap_or_else(|| difficulty.height()),
+            args.mines.unwrap_or_else(|| difficulty.mines()),
+        )
+        .with_duds(args.duds)
+        .with_topology(args.topology.into())
+        .with_neighborhood(args.neighborhood.to_neighborhood(args.radius));
+        let config = args.seed.map_or(config, |seed| config.with_seed(seed));
+        let config = args.time_limit.map_or(config, |seconds| {
+            config.with_time_limit(Duration::from_secs(seconds.get()))
+        });
+        let config = args
+            .move_limit
+            .map_or(config, |moves| config.with_move_limit(moves));
+        let config = args.lives.map_or(config, |lives| config.with_lives(lives));
+        let config = args
+            .mines_per_field
+            .map_or(config, |mines| config.with_mines_per_field(mines));
+        args.no_guess
+            .map_or(config, |attempts| config.with_no_guess(attempts))
+            .try_into()
     }
 }
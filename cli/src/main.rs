@@ -1,5 +1,6 @@
 //! A mine sweeping game with optional dud mines.
 
+use std::fs::write;
 use std::process::ExitCode;
 
 use clap::Parser;
@@ -12,40 +13,87 @@ use self::io::read_until_valid;
 mod action;
 mod args;
 mod io;
+mod neighborhood;
+mod preset;
+mod topology;
 
-const HELP: [&str; 4] = [
+const HELP: [&str; 10] = [
     "Visit a field:                x y",
     "Toggle flag on a field:       !x y",
+    "Add a flag to a field:        >x y",
+    "Remove a flag from a field:   <x y",
+    "Toggle question mark:         ?x y",
+    "Visit around a cleared field: +x y",
     "Visit all non-flagged fields: !!",
+    "Pause:                        pause | p",
+    "Resume:                       resume | r",
     "Abort:                        exit | quit | q",
 ];
 
 fn main() -> ExitCode {
-    match Game::try_from(Args::parse()) {
+    let args = Args::parse();
+    let rawvf = args.rawvf.clone();
+
+    match Game::try_from(args) {
         Ok(mut game) => {
             println!("{game}\n");
             print_help();
 
-            while let Some(state) = get_action().and_then(|action| game.next_round(action)) {
+            while let Some(state) = get_action(&mut game).and_then(|action| game.next_round(action))
+            {
                 match state {
                     State::GameOver(outcome) => {
                         println!("{game}\n");
-                        return match outcome {
-                            Outcome::Won(end) => {
-                                println!(
-                                    "\nYou won the game.\nTime: {:?}",
-                                    end.duration_since(game.start())
-                                );
+                        println!("Seed: {}", game.seed());
+
+                        if let Some((path, video)) = rawvf.as_ref().zip(game.rawvf())
+                            && let Err(error) = write(path, video.to_string())
+                        {
+                            eprintln!("Could not write RAW video: {error}");
+                        }
+
+                        let exit_code = match outcome {
+                            Outcome::Won(_) => {
+                                println!("\nYou won the game.\nTime: {:?}", game.duration());
                                 ExitCode::SUCCESS
                             }
                             Outcome::Lost(_) => {
                                 println!("\nYou lost the game.");
                                 ExitCode::FAILURE
                             }
+                            Outcome::TimedOut(_) => {
+                                println!("\nYou ran out of time.");
+                                ExitCode::FAILURE
+                            }
+                            Outcome::OutOfMoves(_) => {
+                                println!("\nYou ran out of moves.");
+                                ExitCode::FAILURE
+                            }
                         };
+
+                        if let Some(statistics) = game.statistics() {
+                            println!("{statistics}");
+                        }
+
+                        return exit_code;
                     }
                     State::InvalidMove => println!("Invalid move."),
-                    State::Continue => println!("{game}\n"),
+                    State::Paused => println!("The game is paused. Enter \"resume\" to continue."),
+                    State::Error(error) => println!("Error: {error}"),
+                    State::Continue => {
+                        println!("{game}\n");
+                        print_status(&game);
+                    }
+                    State::DudTriggered(coordinate) => {
+                        println!("{game}\n");
+                        print_status(&game);
+                        println!("Phew! The mine at {coordinate} was a dud.");
+                    }
+                    State::LifeLost(coordinate) => {
+                        println!("{game}\n");
+                        print_status(&game);
+                        println!("Boom! The mine at {coordinate} cost you a life.");
+                    }
                 }
             }
         }
@@ -61,12 +109,38 @@ fn print_help() {
     }
 }
 
-fn get_action() -> Option<rustymines::Action> {
-    match read_until_valid("Enter action: ") {
-        Action::Action(action) => Some(action),
-        Action::Abort => {
-            println!("Bye!");
-            None
+fn print_status(game: &Game) {
+    if game.config().lives().get() > 1 {
+        println!("Lives left: {}", game.lives());
+    }
+
+    if let Some(time) = game.remaining_time() {
+        println!("Time left: {}s", time.as_secs());
+    }
+
+    if let Some(moves) = game.remaining_moves() {
+        println!("Moves left: {moves}");
+    }
+}
+
+fn get_action(game: &mut Game) -> Option<rustymines::Action> {
+    loop {
+        match read_until_valid("Enter action: ") {
+            Action::Action(action) => return Some(action),
+            Action::Pause => {
+                if game.pause() {
+                    println!("{game}");
+                }
+            }
+            Action::Resume => {
+                if game.resume() {
+                    println!("{game}\n");
+                }
+            }
+            Action::Abort => {
+                println!("Bye!");
+                return None;
+            }
         }
     }
 }
@@ -0,0 +1,33 @@
+use std::num::NonZero;
+
+use clap::ValueEnum;
+
+/// Neighborhoods of fields selectable on the command line.
+#[derive(Clone, Copy, Debug, Default, ValueEnum)]
+pub enum Neighborhood {
+    /// Square fields with eight neighbors.
+    #[default]
+    Moore,
+    /// Hexagonal fields with six neighbors.
+    Hexagonal,
+    /// Square fields with their four orthogonal neighbors.
+    #[value(alias = "cross")]
+    VonNeumann,
+    /// Square fields with the eight fields a knight can move to in chess as neighbors.
+    Knight,
+    /// Square fields with all fields within the radius as neighbors.
+    Radius,
+}
+
+impl Neighborhood {
+    /// Return the respective neighborhood of the game, using the given radius if applicable.
+    pub const fn to_neighborhood(self, radius: NonZero<u8>) -> rustymines::Neighborhood {
+        match self {
+            Self::Moore => rustymines::Neighborhood::Moore,
+            Self::Hexagonal => rustymines::Neighborhood::Hexagonal,
+            Self::VonNeumann => rustymines::Neighborhood::VonNeumann,
+            Self::Knight => rustymines::Neighborhood::Knight,
+            Self::Radius => rustymines::Neighborhood::Radius(radius),
+        }
+    }
+}
@@ -0,0 +1,24 @@
+use clap::ValueEnum;
+use rustymines::Difficulty;
+
+/// Difficulty presets selectable on the command line.
+#[derive(Clone, Copy, Debug, Default, ValueEnum)]
+pub enum Preset {
+    /// A 9x9 board with 10 mines.
+    #[default]
+    Beginner,
+    /// A 16x16 board with 40 mines.
+    Intermediate,
+    /// A 30x16 board with 99 mines.
+    Expert,
+}
+
+impl From<Preset> for Difficulty {
+    fn from(preset: Preset) -> Self {
+        match preset {
+            Preset::Beginner => Self::Beginner,
+            Preset::Intermediate => Self::Intermediate,
+            Preset::Expert => Self::Expert,
+        }
+    }
+}
@@ -0,0 +1,20 @@
+use clap::ValueEnum;
+
+/// Board topologies selectable on the command line.
+#[derive(Clone, Copy, Debug, Default, ValueEnum)]
+pub enum Topology {
+    /// The board ends at its edges.
+    #[default]
+    Flat,
+    /// The board wraps around at its edges.
+    Torus,
+}
+
+impl From<Topology> for rustymines::Topology {
+    fn from(topology: Topology) -> Self {
+        match topology {
+            Topology::Flat => Self::Flat,
+            Topology::Torus => Self::Torus,
+        }
+    }
+}
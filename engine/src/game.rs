@@ -1,29 +1,77 @@
 use std::fmt;
 use std::num::NonZero;
-use std::time::{Duration, Instant};
+use std::time::{Duration, Instant, SystemTime};
 
-use grid2d::Coordinate;
+use grid2d::Grid;
 
 use self::action::Action;
-use self::board::field::View;
+use self::board::field::{Field, View};
 use self::board::{Board, MoveResult};
+use self::change::Change;
+use self::clock::{Clock, SystemClock};
+use self::config::Config;
+use self::difficulty::Difficulty;
+use self::history::{History, Move};
+use self::layout::Layout;
+use self::neighborhood::Neighborhood;
 use self::outcome::Outcome;
+use self::rawvf::RawVf;
+use self::replay::{Event, Replay};
 use self::state::State;
-use crate::Error;
+use self::statistics::Statistics;
+use self::topology::Topology;
+use crate::{Error, Hint, Solver};
 
 pub mod action;
 pub mod board;
+pub mod change;
+pub mod clock;
+pub mod config;
+#[cfg(feature = "serde")]
+mod coordinate;
+pub mod difficulty;
+mod ending;
+mod history;
+pub mod layout;
+pub mod neighborhood;
 pub mod outcome;
+pub mod rawvf;
+pub mod replay;
+#[cfg(feature = "serde")]
+mod snapshot;
 pub mod state;
+pub mod statistics;
+pub mod topology;
 
 /// The game object with the board and metadata.
-#[derive(Debug)]
-pub struct Game {
+///
+/// All times are taken from the game's [`Clock`], which is the system's clock by default.
+///
+/// With the `serde` feature enabled, a game can be serialized and restored, to continue playing
+/// where it stopped.
+#[derive(Clone, Debug)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(
+        into = "snapshot::Snapshot",
+        try_from = "snapshot::Snapshot",
+        bound = "C: Clone + Default"
+    )
+)]
+pub struct Game<C: Clock = SystemClock> {
     board: Board,
-    mines: u8,
-    duds: u8,
+    config: Config,
+    clock: C,
     start: Instant,
+    started: SystemTime,
+    paused: Option<Instant>,
+    pauses: Duration,
     outcome: Option<Outcome>,
+    moves: usize,
+    history: History,
+    undos: usize,
+    replay: Replay,
 }
 
 impl Game {
@@ -35,24 +83,83 @@ impl Game {
     pub fn new(
         width: NonZero<usize>,
         height: NonZero<usize>,
-        mines: u8,
-        duds: u8,
+        mines: usize,
+        duds: usize,
     ) -> Result<Self, Error> {
-        Board::new(width, height, mines, duds).map(|board| Self {
+        Config::new(width, height, mines).with_duds(duds).try_into()
+    }
+
+    /// Crate a new game with the board size and amount of mines of the given difficulty.
+    ///
+    /// # Errors
+    ///
+    /// Return an [`Error`] if the grid size or amount of mines of a custom difficulty is out of bounds.
+    pub fn from_difficulty(difficulty: Difficulty) -> Result<Self, Error> {
+        Config::from(difficulty).try_into()
+    }
+
+    /// Crate a new game with mines and duds placed according to the given layout.
+    ///
+    /// # Errors
+    ///
+    /// Return an [`Error`] if the layout does not leave any field free of mines.
+    pub fn from_layout(layout: &Layout) -> Result<Self, Error> {
+        Self::with_layout(layout, SystemClock)
+    }
+}
+
+impl<C: Clock> Game<C> {
+    /// Crate a new game with the given configuration, which takes its times from the given clock.
+    ///
+    /// # Errors
+    ///
+    /// Return an [`Error`] if the grid size or amount of mines and duds is out of bounds.
+    pub fn with_clock(config: Config, clock: C) -> Result<Self, Error> {
+        let seed = config.seed().unwrap_or_else(rand::random);
+        let config = config.with_seed(seed);
+        Board::new(&config, seed).map(|board| Self {
             board,
-            mines,
-            duds,
-            start: Instant::now(),
+            config,
+            start: clock.now(),
+            started: clock.system_time(),
+            clock,
+            paused: None,
+            pauses: Duration::ZERO,
             outcome: None,
+            moves: 0,
+            history: History::default(),
+            undos: 0,
+            replay: Replay::new(config, None),
         })
     }
 
+    /// Crate a new game with mines and duds placed according to the given layout,
+    /// which takes its times from the given clock.
+    ///
+    /// # Errors
+    ///
+    /// Return an [`Error`] if the layout does not leave any field free of mines.
+    pub fn with_layout(layout: &Layout, clock: C) -> Result<Self, Error> {
+        let mut game = Self::with_clock(Config::from(layout), clock)?;
+        game.board.place(layout);
+        game.replay = Replay::new(game.config, Some(layout.clone()));
+        Ok(game)
+    }
+
+    /// Return the layout of mines and duds, once they have been placed on the first move.
+    ///
+    /// The layout can be exported as text or in the `.mbf` format.
+    #[must_use]
+    pub fn layout(&self) -> Option<Layout> {
+        self.board.layout()
+    }
+
     /// Return an iterator of field views over the game board's rows.
     pub fn rows(&self) -> impl Iterator<Item = impl Iterator<Item = View>> {
         self.board
             .fields()
             .rows()
-            .map(|row| row.map(|field| field.view(self.is_over())))
+            .map(|row| row.map(|&field| self.view(field)))
     }
 
     /// Return an iterator of field views over the game board's columns.
@@ -60,27 +167,40 @@ impl Game {
         self.board
             .fields()
             .columns()
-            .map(|column| column.map(|field| field.view(self.is_over())))
+            .map(|column| column.map(|&field| self.view(field)))
     }
 
     /// Return an iterator oif field views over the game board's fields.
+    ///
+    /// While the game is paused, all fields are covered.
     pub fn iter(&self) -> impl Iterator<Item = View> {
-        self.board
-            .fields()
-            .iter()
-            .map(|field| field.view(self.is_over()))
+        self.board.fields().iter().map(|&field| self.view(field))
     }
 
     /// Return the amount of mines in the game.
     #[must_use]
-    pub const fn mines(&self) -> u8 {
-        self.mines
+    pub const fn mines(&self) -> usize {
+        self.config.mines()
     }
 
     /// Return the amount of duds in the game.
     #[must_use]
-    pub const fn duds(&self) -> u8 {
-        self.duds
+    pub const fn duds(&self) -> usize {
+        self.config.duds()
+    }
+
+    /// Return the seed used to place mines and duds.
+    #[must_use]
+    pub const fn seed(&self) -> u64 {
+        self.board.seed()
+    }
+
+    /// Return the configuration of the game.
+    ///
+    /// The returned configuration always contains the game's seed.
+    #[must_use]
+    pub const fn config(&self) -> Config {
+        self.config
     }
 
     /// Return the amount of flags on the game board.
@@ -95,18 +215,164 @@ impl Game {
         self.start
     }
 
+    /// Return the wall-clock time of when the game was started.
+    #[must_use]
+    pub const fn start_time(&self) -> SystemTime {
+        self.started
+    }
+
+    /// Return the wall-clock time of when the game ended, if it has ended.
+    #[must_use]
+    pub fn end_time(&self) -> Option<SystemTime> {
+        self.outcome
+            .map(|outcome| self.started + outcome.end().duration_since(self.start))
+    }
+
+    /// Return the clock of the game.
+    #[must_use]
+    pub const fn clock(&self) -> &C {
+        &self.clock
+    }
+
+    /// Return the clock of the game mutably, e.g. to advance a [`ManualClock`](clock::ManualClock).
+    #[must_use]
+    pub const fn clock_mut(&mut self) -> &mut C {
+        &mut self.clock
+    }
+
     /// Return the outcome, if the game has ended.
     #[must_use]
     pub const fn outcome(&self) -> Option<Outcome> {
         self.outcome
     }
 
-    /// Return the duration for which the game has been running.
+    /// Return the duration for which the game has been running, excluding pauses.
     #[must_use]
     pub fn duration(&self) -> Duration {
         self.outcome
-            .map_or_else(Instant::now, Outcome::end)
+            .map(Outcome::end)
+            .or(self.paused)
+            .unwrap_or_else(|| self.clock.now())
             .duration_since(self.start)
+            .saturating_sub(self.pauses)
+    }
+
+    /// Return the amount of lives left.
+    ///
+    /// Unless the game was configured with more lives, the only life is lost with the first mine
+    /// stepped onto, which is not a dud.
+    #[must_use]
+    pub fn lives(&self) -> usize {
+        self.board.lives()
+    }
+
+    /// Return the amount of moves made during the game.
+    ///
+    /// Only moves which changed the board are counted, i.e. invalid moves, moves rejected due to
+    /// errors and moves without effect, such as visiting a field again, are not.
+    #[must_use]
+    pub const fn moves(&self) -> usize {
+        self.moves
+    }
+
+    /// Return the time left until the time limit runs out, if one has been set.
+    ///
+    /// The game ends with [`Outcome::TimedOut`] on the next attempted move after the time ran out.
+    #[must_use]
+    pub fn remaining_time(&self) -> Option<Duration> {
+        self.config
+            .time_limit()
+            .map(|limit| limit.saturating_sub(self.duration()))
+    }
+
+    /// Return the amount of moves left until the move limit is reached, if one has been set.
+    #[must_use]
+    pub const fn remaining_moves(&self) -> Option<usize> {
+        match self.config.move_limit() {
+            Some(limit) => Some(limit.get().saturating_sub(self.moves)),
+            None => None,
+        }
+    }
+
+    /// Return `true` if the game is paused.
+    #[must_use]
+    pub const fn is_paused(&self) -> bool {
+        self.paused.is_some()
+    }
+
+    /// Pause the game.
+    ///
+    /// While the game is paused, the clock stops, moves are rejected and all fields are covered.
+    ///
+    /// Return `true` if the game has been paused, or `false` if it is over or already paused.
+    pub fn pause(&mut self) -> bool {
+        if self.expire() || self.is_over() || self.is_paused() {
+            return false;
+        }
+
+        self.paused = Some(self.clock.now());
+        true
+    }
+
+    /// Resume the paused game.
+    ///
+    /// Return `true` if the game has been resumed, or `false` if it was not paused.
+    pub fn resume(&mut self) -> bool {
+        let Some(paused) = self.paused.take() else {
+            return false;
+        };
+
+        self.pauses += self.clock.now().duration_since(paused);
+        true
+    }
+
+    /// Return the amount of moves that have been undone during the game.
+    #[must_use]
+    pub const fn undos(&self) -> usize {
+        self.undos
+    }
+
+    /// Return an iterator over the actions that have been applied to the game board.
+    ///
+    /// Actions that did not change the board as well as undone actions are not included.
+    pub fn actions(&self) -> impl Iterator<Item = Action> {
+        self.history.actions()
+    }
+
+    /// Return a hint for a field, which is provably safe or provably contains a mine.
+    ///
+    /// Return `None` if the game is over or paused, fields may hold multiple mines
+    /// or no field can be deduced from the visible board.
+    #[must_use]
+    pub fn hint(&self) -> Option<Hint> {
+        if self.is_over() || self.is_paused() || self.config.mines_per_field().get() > 1 {
+            return None;
+        }
+
+        Solver::new(&self.board.views(false))
+            .with_topology(self.config.topology())
+            .with_neighborhood(self.config.neighborhood())
+            .hint()
+    }
+
+    /// Return the probability of each field to contain a live mine.
+    ///
+    /// The probabilities are based on the visible board and the total amounts of mines and duds.
+    /// See [`Solver::probabilities`] for details on large boards.
+    /// If fields may hold multiple mines, the probabilities are approximated as if each field
+    /// held at most one mine.
+    #[must_use]
+    pub fn probabilities(&self) -> Grid<f64> {
+        let mut views =
+            Grid::new_default(self.board.fields().width(), self.board.fields().height());
+        views
+            .iter_mut()
+            .zip(self.iter())
+            .for_each(|(target, view)| *target = view);
+        Solver::new(&views)
+            .with_topology(self.config.topology())
+            .with_neighborhood(self.config.neighborhood())
+            .probabilities(self.mines(), self.duds())
     }
 
     /// Return `true` if the game is over.
@@ -118,37 +384,287 @@ impl Game {
     /// Play the next round.
     ///
     /// Return `Some(State)` if the player did not request to abort the game, otherwise `None`.
+    /// While the game is paused, the move is rejected with [`State::Paused`].
+    /// If the time limit has run out, the move is rejected and the game ends with
+    /// [`Outcome::TimedOut`].
     pub fn next_round(&mut self, action: Action) -> Option<State> {
-        if self.is_over() {
+        if self.is_paused() {
+            return Some(State::Paused);
+        }
+
+        if !self.is_over() && self.expire() {
+            return self.outcome.map(State::GameOver);
+        }
+
+        self.make_move(action)
+            .map(|result| match (result, self.outcome) {
+                (MoveResult::InvalidPosition, _) => State::InvalidMove,
+                (MoveResult::Error(error), _) => State::Error(error),
+                (_, Some(outcome)) => State::GameOver(outcome),
+                (MoveResult::DudTriggered(coordinate), None) => State::DudTriggered(coordinate),
+                (MoveResult::LifeLost(coordinate), None) => State::LifeLost(coordinate),
+                (MoveResult::Continue | MoveResult::Lost | MoveResult::Won, None) => {
+                    State::Continue
+                }
+            })
+    }
+
+    /// Play the next round and return the changes of the fields' views alongside the state.
+    ///
+    /// The changes include fields uncovered by flood fill, flags and fields revealed at the end
+    /// of the game, so that frontends can update the board incrementally.
+    ///
+    /// Return `None` if the game is already over.
+    pub fn next_round_with_changes(&mut self, action: Action) -> Option<(State, Vec<Change>)> {
+        let views: Vec<_> = self.iter().collect();
+        let state = self.next_round(action)?;
+        let changes = self
+            .board
+            .fields()
+            .enumerate()
+            .zip(views)
+            .map(|((coordinate, &field), before)| (coordinate, before, self.view(field)))
+            .filter(|&(_, before, after)| before != after)
+            .map(|(coordinate, _, view)| Change::new(coordinate, view))
+            .collect();
+        Some((state, changes))
+    }
+
+    /// Return the statistics of the game, once it has been won.
+    ///
+    /// Clicks are all visits, flags and chords recorded, including those undone.
+    /// Lost games have no statistics, since only part of the board's 3BV has been solved.
+    #[must_use]
+    pub fn statistics(&self) -> Option<Statistics> {
+        if !matches!(self.outcome, Some(Outcome::Won(_))) {
             return None;
         }
 
-        Some(match action {
-            Action::Visit(coordinate) => self.visit(coordinate).into(),
-            Action::ToggleFlag(coordinate) => self.board.toggle_flag(coordinate).into(),
-            Action::VisitAllNonFlaggedFields => self.board.visit_non_flagged_fields().into(),
+        let clicks = self
+            .replay
+            .entries()
+            .iter()
+            .filter(|entry| {
+                matches!(
+                    entry.event(),
+                    Event::Action {
+                        action: Action::Visit(_)
+                            | Action::ToggleFlag(_)
+                            | Action::RaiseFlag(_)
+                            | Action::LowerFlag(_)
+                            | Action::ToggleMark(_)
+                            | Action::Chord(_),
+                        ..
+                    }
+                )
+            })
+            .count();
+        self.layout().map(|layout| {
+            Statistics::new(
+                layout.bbbv(self.config.topology(), self.config.neighborhood()),
+                clicks,
+                self.duration(),
+            )
         })
     }
 
-    /// Visit the given coordinate.
-    fn visit(&mut self, coordinate: Coordinate) -> MoveResult {
-        match self.board.visit(coordinate) {
+    /// Return the game as a video in the RAW video format, once mines have been placed.
+    ///
+    /// The video can be written to a `.rawvf` file to be loaded in common video viewers.
+    ///
+    /// Return `None` if moves have been undone, all non-flagged fields have been visited,
+    /// the game has duds or more than one life, or the board is not a classic one with flat
+    /// topology, the Moore neighborhood and a single mine per field, since the format cannot
+    /// represent any of these.
+    #[must_use]
+    pub fn rawvf(&self) -> Option<RawVf<'_, C>> {
+        if self.config.topology() != Topology::Flat
+            || self.config.neighborhood() != Neighborhood::Moore
+            || self.config.mines_per_field().get() > 1
+            || self.config.duds() > 0
+            || self.config.lives().get() > 1
+            || self.undos > 0
+            || self.replay.entries().iter().any(|entry| {
+                matches!(
+                    entry.event(),
+                    Event::Action {
+                        action: Action::VisitAllNonFlaggedFields,
+                        ..
+                    }
+                )
+            })
+        {
+            return None;
+        }
+
+        self.layout().map(|layout| RawVf::new(self, layout))
+    }
+
+    /// Return the recording of all actions taken so far.
+    #[must_use]
+    pub const fn replay(&self) -> &Replay {
+        &self.replay
+    }
+
+    /// Make a move and record it.
+    ///
+    /// Return `None` if the game is already over.
+    fn make_move(&mut self, action: Action) -> Option<MoveResult> {
+        if self.is_over() {
+            return None;
+        }
+
+        let fields = self.board.fields().clone();
+        let init = self.board.init();
+        let result = match action {
+            Action::Visit(coordinate) => self.board.visit(coordinate),
+            Action::ToggleFlag(coordinate) => self.board.toggle_flag(coordinate),
+            Action::RaiseFlag(coordinate) => self.board.raise_flag(coordinate),
+            Action::LowerFlag(coordinate) => self.board.lower_flag(coordinate),
+            Action::ToggleMark(coordinate) => self.board.toggle_mark(coordinate),
+            Action::Chord(coordinate) => self.board.chord(coordinate),
+            Action::VisitAllNonFlaggedFields => self.board.visit_non_flagged_fields(),
+        };
+
+        let record = Move::new(action, &fields, init, &self.board);
+
+        if record.is_some() {
+            self.moves += 1;
+        }
+
+        let result = self.conclude(result);
+
+        if self.config.undo()
+            && let Some(record) = record
+        {
+            self.history.record(record.with_outcome(self.outcome));
+        }
+
+        self.replay
+            .record(self.elapsed(), Event::Action { action, result });
+        Some(result)
+    }
+
+    /// Undo the last move.
+    ///
+    /// This also revives a game that has been lost or won by the move.
+    ///
+    /// Return `true` if a move has been undone, or `false` if there was no move to undo,
+    /// undoing moves is disabled, the game is paused or it ran out of time or moves.
+    pub fn undo(&mut self) -> bool {
+        self.expire();
+
+        if self.is_paused() || self.outcome.is_some_and(Outcome::exceeded_limit) {
+            return false;
+        }
+
+        let Some(record) = self.history.undo() else {
+            return false;
+        };
+
+        record.undo(&mut self.board);
+        self.outcome = None;
+        self.undos += 1;
+        self.replay.record(self.elapsed(), Event::Undo);
+        true
+    }
+
+    /// Redo the last undone move.
+    ///
+    /// Return `true` if a move has been redone, or `false` if there was no move to redo,
+    /// the game is paused or it is already over, e.g. because it ran out of time.
+    pub fn redo(&mut self) -> bool {
+        self.expire();
+
+        if self.is_paused() || self.is_over() {
+            return false;
+        }
+
+        let Some(record) = self.history.redo() else {
+            return false;
+        };
+
+        let now = self.clock.now();
+        self.outcome = record.redo(&mut self.board).map(|ending| ending.at(now));
+        self.replay.record(self.elapsed(), Event::Redo);
+        true
+    }
+
+    /// Record the outcome of the game, if the given move ended it.
+    fn conclude(&mut self, result: MoveResult) -> MoveResult {
+        match result {
             MoveResult::Lost => {
-                self.outcome.replace(Outcome::Lost(Instant::now()));
+                self.outcome.replace(Outcome::Lost(self.clock.now()));
                 MoveResult::Lost
             }
             MoveResult::Won => {
-                self.outcome.replace(Outcome::Won(Instant::now()));
+                self.outcome.replace(Outcome::Won(self.clock.now()));
                 MoveResult::Won
             }
-            result => result,
+            result => {
+                if self.remaining_moves() == Some(0) {
+                    self.outcome.replace(Outcome::OutOfMoves(self.clock.now()));
+                }
+
+                result
+            }
+        }
+    }
+
+    /// End the game with [`Outcome::TimedOut`], if its time limit has run out.
+    ///
+    /// The game ends at the instant the time ran out, rather than when this was noticed.
+    ///
+    /// Return `true` if the game has timed out.
+    fn expire(&mut self) -> bool {
+        if let Some(limit) = self.config.time_limit()
+            && !self.is_over()
+            && self.duration() >= limit
+        {
+            let end = self
+                .pauses
+                .checked_add(limit)
+                .and_then(|offset| self.start.checked_add(offset))
+                .unwrap_or_else(|| self.clock.now());
+            self.outcome.replace(Outcome::TimedOut(end));
+            self.replay.record(limit, Event::Expire);
+        }
+
+        matches!(self.outcome, Some(Outcome::TimedOut(_)))
+    }
+
+    /// Return the time elapsed on the clock since the start of the game, excluding pauses.
+    fn elapsed(&self) -> Duration {
+        self.clock
+            .now()
+            .duration_since(self.start)
+            .saturating_sub(self.pauses)
+    }
+
+    /// Return the view of the given field, which is covered while the game is paused.
+    const fn view(&self, field: Field) -> View {
+        if self.is_paused() {
+            View::Covered
+        } else {
+            field.view(self.is_over())
         }
     }
 }
 
-impl fmt::Display for Game {
+impl TryFrom<Config> for Game {
+    type Error = Error;
+
+    fn try_from(config: Config) -> Result<Self, Self::Error> {
+        Self::with_clock(config, SystemClock)
+    }
+}
+
+impl<C: Clock> fmt::Display for Game<C> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        if self.is_over() {
+        if self.is_paused() {
+            self.board.fmt_with(f, |_| View::Covered)?;
+            writeln!(f, "\n\nPaused")
+        } else if self.is_over() {
             write!(f, "{:#}", self.board)
         } else {
             writeln!(f, "{}", self.board)?;
@@ -156,3 +672,273 @@ impl fmt::Display for Game {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use std::num::NonZero;
+    use std::time::Duration;
+
+    use grid2d::Coordinate;
+
+    use super::{Action, Config, Game, Layout, Outcome, State, Topology};
+    use crate::ManualClock;
+
+    fn game(layout: &str) -> Game<ManualClock> {
+        let layout: Layout = layout.parse().expect("Layout should be valid.");
+        Game::with_layout(&layout, ManualClock::default()).expect("Layout should be playable.")
+    }
+
+    fn board(game: &Game<ManualClock>) -> Vec<String> {
+        game.rows()
+            .map(|row| row.map(|view| view.to_string()).collect())
+            .collect()
+    }
+
+    fn game_with(layout: &str, config: impl FnOnce(Config) -> Config) -> Game<ManualClock> {
+        let layout: Layout = layout.parse().expect("Layout should be valid.");
+        let mut game = Game::with_clock(config(Config::from(&layout)), ManualClock::default())
+            .expect("Config should be valid.");
+        game.board.place(&layout);
+        game
+    }
+
+    fn timed_game() -> Game<ManualClock> {
+        let config = Config::new(
+            NonZero::new(5).expect("Width should be non-zero."),
+            NonZero::new(5).expect("Height should be non-zero."),
+            3,
+        )
+        .with_seed(0)
+        .with_time_limit(Duration::from_secs(10));
+        Game::with_clock(config, ManualClock::default()).expect("Config should be valid.")
+    }
+
+    #[test]
+    fn flood_fill() {
+        let mut game = game("*...\n....\n....");
+        assert!(matches!(
+            game.next_round(Action::Visit(Coordinate::new(3, 2))),
+            Some(State::GameOver(Outcome::Won(_)))
+        ));
+        assert_eq!(board(&game), ["*1  ", "11  ", "    "]);
+    }
+
+    #[test]
+    fn flood_fill_stops_at_numbers() {
+        let mut game = game("..*..\n..*..\n..*..");
+        assert!(matches!(
+            game.next_round(Action::Visit(Coordinate::new(0, 0))),
+            Some(State::Continue)
+        ));
+        assert_eq!(board(&game), [" 2■■■", " 3■■■", " 2■■■"]);
+    }
+
+    #[test]
+    fn first_move_keeps_layout() {
+        let mut game = game("*..\n...");
+        assert!(matches!(
+            game.next_round(Action::Visit(Coordinate::new(0, 0))),
+            Some(State::GameOver(Outcome::Lost(_)))
+        ));
+    }
+
+    #[test]
+    fn flags_protect_fields() {
+        let mut game = game("*..\n...");
+        game.next_round(Action::ToggleFlag(Coordinate::new(0, 0)));
+        assert!(matches!(
+            game.next_round(Action::Visit(Coordinate::new(0, 0))),
+            Some(State::Continue)
+        ));
+        assert_eq!(game.flags(), 1);
+        assert!(!game.is_over());
+    }
+
+    #[test]
+    fn chord() {
+        let mut game = game("*.*\n...\n*..");
+        game.next_round(Action::Visit(Coordinate::new(1, 1)));
+        game.next_round(Action::ToggleFlag(Coordinate::new(0, 0)));
+        game.next_round(Action::ToggleFlag(Coordinate::new(2, 0)));
+        game.next_round(Action::ToggleFlag(Coordinate::new(0, 2)));
+        game.next_round(Action::Chord(Coordinate::new(1, 1)));
+        assert_eq!(board(&game), ["⚐2⚐", "231", "⚐1 "]);
+        assert!(matches!(game.outcome(), Some(Outcome::Won(_))));
+    }
+
+    #[test]
+    fn chord_with_misplaced_flag() {
+        let mut game = game("*..\n...\n...");
+        game.next_round(Action::Visit(Coordinate::new(1, 1)));
+        game.next_round(Action::ToggleFlag(Coordinate::new(2, 2)));
+        game.next_round(Action::Chord(Coordinate::new(1, 1)));
+        assert!(matches!(game.outcome(), Some(Outcome::Lost(_))));
+    }
+
+    #[test]
+    fn undo_and_redo() {
+        let mut game = game("*..\n...\n...");
+        game.next_round(Action::Visit(Coordinate::new(0, 0)));
+        assert!(game.undo());
+        assert!(!game.is_over());
+        assert_eq!(board(&game), ["■■■", "■■■", "■■■"]);
+        assert!(game.redo());
+        assert!(matches!(game.outcome(), Some(Outcome::Lost(_))));
+    }
+
+    #[test]
+    fn time_limit() {
+        let mut game = timed_game();
+        game.next_round(Action::Visit(Coordinate::new(2, 2)));
+        game.clock_mut().advance(Duration::from_secs(20));
+        assert!(matches!(
+            game.next_round(Action::Visit(Coordinate::new(0, 0))),
+            Some(State::GameOver(Outcome::TimedOut(_)))
+        ));
+        assert_eq!(game.duration(), Duration::from_secs(10));
+        assert!(!game.undo());
+    }
+
+    #[test]
+    fn redo_after_time_limit() {
+        let mut game = timed_game();
+        game.next_round(Action::Visit(Coordinate::new(2, 2)));
+        assert!(game.undo());
+        game.clock_mut().advance(Duration::from_secs(20));
+        assert!(!game.redo());
+        assert!(matches!(game.outcome(), Some(Outcome::TimedOut(_))));
+    }
+
+    #[test]
+    fn replay_reproduces_time_out() {
+        let mut game = timed_game();
+        game.next_round(Action::Visit(Coordinate::new(2, 2)));
+        game.clock_mut().advance(Duration::from_secs(20));
+        game.next_round(Action::Visit(Coordinate::new(0, 0)));
+        let replayed = game.replay().play().expect("Replay should not diverge.");
+        assert!(matches!(replayed.outcome(), Some(Outcome::TimedOut(_))));
+        assert_eq!(replayed.duration(), game.duration());
+    }
+
+    #[test]
+    fn moves_without_effect_are_not_counted() {
+        let mut game = game("*..\n.*.\n...");
+        game.next_round(Action::Visit(Coordinate::new(2, 2)));
+        game.next_round(Action::Visit(Coordinate::new(2, 2)));
+        game.next_round(Action::Visit(Coordinate::new(5, 5)));
+        game.next_round(Action::Chord(Coordinate::new(2, 2)));
+        assert_eq!(game.moves(), 1);
+        game.next_round(Action::ToggleFlag(Coordinate::new(0, 0)));
+        assert_eq!(game.moves(), 2);
+    }
+
+    #[test]
+    fn pause_stops_the_clock() {
+        let mut game = game("*..\n.*.\n...");
+        game.clock_mut().advance(Duration::from_secs(2));
+        assert!(game.pause());
+        game.clock_mut().advance(Duration::from_secs(10));
+        assert_eq!(game.duration(), Duration::from_secs(2));
+        assert!(matches!(
+            game.next_round(Action::Visit(Coordinate::new(2, 2))),
+            Some(State::Paused)
+        ));
+        assert_eq!(board(&game), ["■■■", "■■■", "■■■"]);
+        assert!(game.resume());
+        game.clock_mut().advance(Duration::from_secs(3));
+        assert_eq!(game.duration(), Duration::from_secs(5));
+        assert!(matches!(
+            game.next_round(Action::Visit(Coordinate::new(2, 2))),
+            Some(State::Continue)
+        ));
+    }
+
+    #[test]
+    fn lives() {
+        let mut game = game_with("*.*\n...\n...", |config| {
+            config.with_lives(NonZero::new(2).expect("Lives should be non-zero."))
+        });
+        assert_eq!(game.lives(), 2);
+        assert!(matches!(
+            game.next_round(Action::Visit(Coordinate::new(0, 0))),
+            Some(State::LifeLost(_))
+        ));
+        assert_eq!(game.lives(), 1);
+        assert!(!game.is_over());
+        assert!(matches!(
+            game.next_round(Action::Visit(Coordinate::new(2, 0))),
+            Some(State::GameOver(Outcome::Lost(_)))
+        ));
+        assert_eq!(game.lives(), 0);
+    }
+
+    #[test]
+    fn torus_flood_fill() {
+        let mut game = game_with("*....\n.....\n.....\n.....\n.....", |config| {
+            config.with_topology(Topology::Torus)
+        });
+        assert!(matches!(
+            game.next_round(Action::Visit(Coordinate::new(2, 2))),
+            Some(State::GameOver(Outcome::Won(_)))
+        ));
+        assert_eq!(board(&game), ["*1  1", "11  1", "     ", "     ", "11  1"]);
+    }
+
+    #[test]
+    fn torus_chord() {
+        let mut game = game_with("*....\n.....\n.....\n.....\n.....", |config| {
+            config.with_topology(Topology::Torus)
+        });
+        game.next_round(Action::Visit(Coordinate::new(4, 4)));
+        assert_eq!(board(&game), ["■■■■■", "■■■■■", "■■■■■", "■■■■■", "■■■■1"]);
+        game.next_round(Action::ToggleFlag(Coordinate::new(0, 0)));
+        assert!(matches!(
+            game.next_round(Action::Chord(Coordinate::new(4, 4))),
+            Some(State::GameOver(Outcome::Won(_)))
+        ));
+    }
+
+    #[test]
+    fn marks_are_not_flags() {
+        let mut game = game("*...\n....\n....");
+        game.next_round(Action::ToggleMark(Coordinate::new(3, 2)));
+        assert_eq!(game.flags(), 0);
+        assert_eq!(board(&game), ["■■■■", "■■■■", "■■■?"]);
+        assert!(matches!(
+            game.next_round(Action::Visit(Coordinate::new(3, 2))),
+            Some(State::GameOver(Outcome::Won(_)))
+        ));
+    }
+
+    #[test]
+    fn flood_fill_clears_marks() {
+        let mut game = game("*...\n....\n....");
+        game.next_round(Action::ToggleMark(Coordinate::new(3, 0)));
+        game.next_round(Action::ToggleMark(Coordinate::new(1, 1)));
+        game.next_round(Action::Visit(Coordinate::new(3, 2)));
+        assert_eq!(board(&game), ["*1  ", "11  ", "    "]);
+        assert!(matches!(game.outcome(), Some(Outcome::Won(_))));
+    }
+
+    #[test]
+    fn same_seed_same_board() {
+        let config = Config::new(
+            NonZero::new(9).expect("Width should be non-zero."),
+            NonZero::new(9).expect("Height should be non-zero."),
+            10,
+        )
+        .with_seed(42)
+        .with_duds(2);
+        let layouts: Vec<_> = (0..2)
+            .map(|_| {
+                let mut game = Game::with_clock(config, ManualClock::default())
+                    .expect("Config should be valid.");
+                game.next_round(Action::Visit(Coordinate::new(4, 4)));
+                game.layout().expect("Mines should be placed.")
+            })
+            .collect();
+        assert_eq!(layouts[0], layouts[1]);
+        assert_eq!(layouts[0].mines(), 10);
+        assert_eq!(layouts[0].duds(), 2);
+    }
+}
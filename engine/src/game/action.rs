@@ -1,12 +1,23 @@
 use grid2d::Coordinate;
 
 /// Possible player actions during a game.
-#[derive(Clone, Copy, Debug)]
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum Action {
     /// Visit the field at the given coordinate.
-    Visit(Coordinate),
+    Visit(#[cfg_attr(feature = "serde", serde(with = "crate::game::coordinate"))] Coordinate),
     /// Toggle the flag on the field at the given coordinate.
-    ToggleFlag(Coordinate),
+    ToggleFlag(#[cfg_attr(feature = "serde", serde(with = "crate::game::coordinate"))] Coordinate),
+    /// Put another flag onto the field at the given coordinate,
+    /// up to the maximum amount of mines per field.
+    RaiseFlag(#[cfg_attr(feature = "serde", serde(with = "crate::game::coordinate"))] Coordinate),
+    /// Remove a flag from the field at the given coordinate.
+    LowerFlag(#[cfg_attr(feature = "serde", serde(with = "crate::game::coordinate"))] Coordinate),
+    /// Toggle the question mark on the field at the given coordinate.
+    ToggleMark(#[cfg_attr(feature = "serde", serde(with = "crate::game::coordinate"))] Coordinate),
+    /// Visit all non-flagged neighbors of the cleared field at the given coordinate,
+    /// if the amount of flags around it matches its amount of adjacent mines.
+    Chord(#[cfg_attr(feature = "serde", serde(with = "crate::game::coordinate"))] Coordinate),
     /// Visit all non-flagged fields.
     VisitAllNonFlaggedFields,
 }
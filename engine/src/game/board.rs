@@ -1,77 +1,165 @@
-use std::collections::HashMap;
+use std::collections::{BTreeSet, HashMap};
 use std::fmt::{Display, Formatter};
+use std::iter::repeat_n;
 use std::num::NonZero;
 
 use grid2d::{Coordinate, Grid};
-use rand::make_rng;
-use rand::rngs::SmallRng;
-use rand::seq::IteratorRandom;
 
 use self::error::Error;
-use self::field::{Field, VisitResult};
+use self::field::{Field, View, VisitResult};
 use self::header::Header;
 pub use self::move_result::MoveResult;
 use self::neighbors_iterator::SafeNeighbors;
+use self::random::Random;
+use crate::game::config::Config;
+use crate::game::layout::{Layout, Tile};
+use crate::game::neighborhood::Neighborhood;
+use crate::game::topology::Topology;
+use crate::solver::{Cell, Solver};
+
+/// The maximum amount of mines a single field can hold.
+const MAX_MINES_PER_FIELD: u8 = 15;
+
+/// The maximum amount of adjacent mines a field can hold.
+const MAX_ADJACENT_MINES: usize = 4095;
 
 pub mod error;
 pub mod field;
 mod header;
 mod move_result;
 mod neighbors_iterator;
+mod random;
+#[cfg(feature = "serde")]
+mod snapshot;
 
 /// The game board, aka. the minefield.
-#[derive(Debug)]
+#[derive(Clone, Debug)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(into = "snapshot::Snapshot", try_from = "snapshot::Snapshot")
+)]
 pub struct Board {
     fields: Grid<Field>,
-    init: Option<(u8, u8)>,
-    rng: SmallRng,
+    init: Option<(usize, usize)>,
+    seed: u64,
+    rng: Random,
+    no_guess: Option<NonZero<usize>>,
+    lives: NonZero<usize>,
+    topology: Topology,
+    neighborhood: Neighborhood,
+    mines_per_field: NonZero<u8>,
 }
 
 impl Board {
-    /// Crate a new game board with the respective parameters.
+    /// Crate a new game board with the respective configuration.
+    ///
+    /// The `seed` determines the placement of mines and duds after the first move.
     ///
     /// # Errors
     ///
     /// Returns an [`Error`] if the grid size or amount of mines and duds is out of bounds.
-    pub fn new(
-        width: NonZero<usize>,
-        height: NonZero<usize>,
-        mines: u8,
-        duds: u8,
-    ) -> Result<Self, Error> {
-        let Some(size) = width.checked_mul(height) else {
+    pub fn new(config: &Config, seed: u64) -> Result<Self, Error> {
+        if config.width().get() > Config::MAX_DIMENSION
+            || config.height().get() > Config::MAX_DIMENSION
+        {
+            return Err(Error::DimensionTooLarge {
+                width: config.width().get(),
+                height: config.height().get(),
+            });
+        }
+
+        let Some(size) = config.width().checked_mul(config.height()) else {
             return Err(Error::FieldTooLarge);
         };
 
-        if size.get() <= mines.into() {
-            return Err(Error::TooManyMines);
+        let mines_per_field = config.mines_per_field();
+
+        if mines_per_field.get() > MAX_MINES_PER_FIELD {
+            return Err(Error::TooManyMinesPerField {
+                mines_per_field: mines_per_field.get(),
+            });
+        }
+
+        if (size.get() - 1).saturating_mul(mines_per_field.get().into()) < config.mines() {
+            return Err(Error::TooManyMines {
+                mines: config.mines(),
+                fields: size.get(),
+            });
         }
 
-        if duds > mines {
-            return Err(Error::TooManyDuds);
+        let neighbors = config.neighborhood().max_neighbors();
+
+        if neighbors.saturating_mul(mines_per_field.get().into()) > MAX_ADJACENT_MINES {
+            return Err(Error::NeighborhoodTooLarge { neighbors });
+        }
+
+        if config.topology() == Topology::Torus
+            && config.neighborhood().is_hexagonal()
+            && !config.height().get().is_multiple_of(2)
+        {
+            return Err(Error::OddHexagonalTorus {
+                height: config.height().get(),
+            });
+        }
+
+        if config.no_guess().is_some() && mines_per_field.get() > 1 {
+            return Err(Error::NoGuessWithMultipleMinesPerField);
+        }
+
+        if config.duds() > config.mines() {
+            return Err(Error::TooManyDuds {
+                duds: config.duds(),
+                mines: config.mines(),
+            });
+        }
+
+        let mined_fields = config.mines().div_ceil(usize::from(mines_per_field.get()));
+
+        if config.duds() > mined_fields {
+            return Err(Error::TooManyDudsForMinedFields {
+                duds: config.duds(),
+                mined_fields,
+            });
         }
 
         Ok(Self {
-            fields: Grid::new_default(width, height),
-            init: Some((mines, duds)),
-            rng: make_rng(),
+            fields: Grid::new_default(config.width(), config.height()),
+            init: Some((config.mines(), config.duds())),
+            seed,
+            rng: Random::new(seed),
+            no_guess: config.no_guess(),
+            lives: config.lives(),
+            topology: config.topology(),
+            neighborhood: config.neighborhood(),
+            mines_per_field,
         })
     }
 
+    /// Return the seed used to place mines and duds.
+    #[must_use]
+    pub const fn seed(&self) -> u64 {
+        self.seed
+    }
+
+    /// Return the amount of lives left.
+    ///
+    /// Each mine, which has been stepped on and is not a dud, costs a life.
+    #[must_use]
+    pub fn lives(&self) -> usize {
+        let explosions = self
+            .fields
+            .iter()
+            .filter(|field| field.has_been_visited() && field.has_mine() && !field.is_dud())
+            .count();
+        self.lives.get().saturating_sub(explosions)
+    }
+
     /// Visit the field at the given coordinate.
     #[must_use]
     pub fn visit(&mut self, coordinate: Coordinate) -> MoveResult {
-        match self.make_move(coordinate) {
-            MoveResult::Lost => MoveResult::Lost,
-            MoveResult::InvalidPosition => MoveResult::InvalidPosition,
-            _ => {
-                if self.all_mines_cleared() {
-                    MoveResult::Won
-                } else {
-                    MoveResult::Continue
-                }
-            }
-        }
+        let result = self.make_move(coordinate);
+        self.conclude(result)
     }
 
     /// Toggle the flag on the field under the given coordinate.
@@ -85,30 +173,125 @@ impl Board {
             })
     }
 
+    /// Put another flag onto the field under the given coordinate,
+    /// up to the maximum amount of mines per field.
+    #[must_use]
+    pub fn raise_flag(&mut self, coordinate: Coordinate) -> MoveResult {
+        let max = self.mines_per_field.get();
+        self.fields
+            .get_mut(coordinate)
+            .map_or(MoveResult::InvalidPosition, |field| {
+                field.raise_flag(max);
+                MoveResult::Continue
+            })
+    }
+
+    /// Remove a flag from the field under the given coordinate.
+    #[must_use]
+    pub fn lower_flag(&mut self, coordinate: Coordinate) -> MoveResult {
+        self.fields
+            .get_mut(coordinate)
+            .map_or(MoveResult::InvalidPosition, |field| {
+                field.lower_flag();
+                MoveResult::Continue
+            })
+    }
+
+    /// Toggle the question mark on the field under the given coordinate.
+    #[must_use]
+    pub fn toggle_mark(&mut self, coordinate: Coordinate) -> MoveResult {
+        self.fields
+            .get_mut(coordinate)
+            .map_or(MoveResult::InvalidPosition, |field| {
+                field.toggle_mark();
+                MoveResult::Continue
+            })
+    }
+
+    /// Visit all non-flagged neighbors of the field under the given coordinate.
+    ///
+    /// This only takes effect, if the field has been cleared and the amount of flags and
+    /// uncovered mines on its neighbors equals its amount of adjacent mines.
+    #[must_use]
+    pub fn chord(&mut self, coordinate: Coordinate) -> MoveResult {
+        let Some(field) = self.fields.get(coordinate) else {
+            return MoveResult::InvalidPosition;
+        };
+
+        if !field.has_been_visited() || field.has_mine() {
+            return MoveResult::Continue;
+        }
+
+        let neighbors = self.neighbor_coordinates(coordinate);
+        let flags: usize = neighbors
+            .iter()
+            .filter_map(|&neighbor| self.fields.get(neighbor))
+            .map(|field| {
+                if field.has_been_visited() {
+                    usize::from(field.mines())
+                } else {
+                    usize::from(field.flags())
+                }
+            })
+            .sum();
+
+        if flags != usize::from(field.adjacent_mines()) {
+            return MoveResult::Continue;
+        }
+
+        let mut result = MoveResult::Continue;
+
+        for neighbor in neighbors {
+            match self.visit_coordinate(neighbor) {
+                MoveResult::Lost => result = MoveResult::Lost,
+                MoveResult::LifeLost(mine)
+                    if matches!(result, MoveResult::Continue | MoveResult::DudTriggered(_)) =>
+                {
+                    result = MoveResult::LifeLost(mine);
+                }
+                MoveResult::DudTriggered(dud) if result == MoveResult::Continue => {
+                    result = MoveResult::DudTriggered(dud);
+                }
+                _ => {}
+            }
+        }
+
+        self.conclude(result)
+    }
+
     /// Visit all fields on the grid, which have not been flagged.
     ///
     /// This is a convenience function to quickly uncover all fields, which are deemed safe to conclude the game.
     #[must_use]
     pub fn visit_non_flagged_fields(&mut self) -> MoveResult {
-        let mut result = MoveResult::Continue;
+        let mut mine = None;
+        let mut dud = None;
 
         if let Some((mines, duds)) = self.init.take() {
-            self.initialize(mines, duds);
+            self.initialize(mines, duds, &[]);
         }
 
-        self.fields.iter_mut().for_each(|field| {
+        self.fields.enumerate_mut().for_each(|(coordinate, field)| {
             // Will only visit non-flagged fields.
-            if field.visit() == VisitResult::SteppedOnMine {
-                result = MoveResult::Lost;
+            match field.visit() {
+                VisitResult::SteppedOnMine => {
+                    mine.get_or_insert(coordinate);
+                }
+                VisitResult::SteppedOnDud => {
+                    dud.get_or_insert(coordinate);
+                }
+                _ => {}
             }
         });
 
-        if result == MoveResult::Lost {
+        if mine.is_some() && self.lives() == 0 {
             MoveResult::Lost
         } else if self.all_mines_cleared() {
             MoveResult::Won
+        } else if let Some(coordinate) = mine {
+            MoveResult::LifeLost(coordinate)
         } else {
-            MoveResult::Continue
+            dud.map_or(MoveResult::Continue, MoveResult::DudTriggered)
         }
     }
 
@@ -117,8 +300,8 @@ impl Board {
     pub fn flags(&self) -> usize {
         self.fields
             .iter()
-            .filter(|field| field.is_flagged())
-            .count()
+            .map(|field| usize::from(field.flags()))
+            .sum()
     }
 
     /// Return a shared reference to the inner fields.
@@ -127,18 +310,110 @@ impl Board {
         &self.fields
     }
 
+    /// Return the views of all fields.
+    #[must_use]
+    pub fn views(&self, game_over: bool) -> Grid<View> {
+        Grid::try_from((
+            self.fields.iter().map(|field| field.view(game_over)),
+            self.fields.width(),
+        ))
+        .expect("Views should have the same size as the fields.")
+    }
+
+    /// Return the amount of mines and duds, which are yet to be placed, if any.
+    #[must_use]
+    pub const fn init(&self) -> Option<(usize, usize)> {
+        self.init
+    }
+
+    /// Restore the given fields and the pending placement of mines and duds.
+    ///
+    /// If the placement is pending again, the random number generator is reset,
+    /// so that the same first move results in the same placement of mines and duds.
+    pub fn restore(
+        &mut self,
+        fields: impl IntoIterator<Item = (Coordinate, Field)>,
+        init: Option<(usize, usize)>,
+    ) {
+        for (coordinate, field) in fields {
+            if let Some(target) = self.fields.get_mut(coordinate) {
+                *target = field;
+            }
+        }
+
+        if init.is_some() && self.init.is_none() {
+            self.rng = Random::new(self.seed);
+        }
+
+        self.init = init;
+    }
+
+    /// Place mines and duds according to the given layout instead of randomly on the first move.
+    ///
+    /// Tiles outside the board are ignored.
+    pub fn place(&mut self, layout: &Layout) {
+        for (coordinate, field) in self.fields.enumerate_mut() {
+            *field = Field::default();
+
+            match layout.tiles().get(coordinate) {
+                Some(Tile::Mine) => field.set_mine(),
+                Some(Tile::Dud) => {
+                    field.set_mine();
+                    field.set_dud();
+                }
+                Some(Tile::Empty) | None => {}
+            }
+        }
+
+        self.update_adjacent_mines();
+        self.init = None;
+    }
+
+    /// Return the layout of mines and duds, once they have been placed.
+    ///
+    /// The layout only records which fields contain mines, but not how many.
+    #[must_use]
+    pub fn layout(&self) -> Option<Layout> {
+        if self.init.is_some() {
+            return None;
+        }
+
+        let mut tiles = Grid::new_default(self.fields.width(), self.fields.height());
+        tiles
+            .iter_mut()
+            .zip(self.fields.iter())
+            .for_each(|(tile, field)| {
+                *tile = match (field.has_mine(), field.is_dud()) {
+                    (true, true) => Tile::Dud,
+                    (true, false) => Tile::Mine,
+                    (false, _) => Tile::Empty,
+                };
+            });
+        Some(tiles.into())
+    }
+
+    /// Return the coordinates of the neighbors of the given coordinate
+    /// according to the board's topology and neighborhood.
+    fn neighbor_coordinates(&self, coordinate: Coordinate) -> Vec<Coordinate> {
+        self.topology.neighbors(
+            self.neighborhood,
+            self.fields.width(),
+            self.fields.height(),
+            coordinate,
+        )
+    }
+
     /// Return the amount of adjacent mines of the respective coordinate on the field.
-    fn count_adjacent_mines(&self, coordinate: &Coordinate) -> u8 {
-        self.fields
-            .neighbors(coordinate)
-            .filter(|(_, field)| field.has_mine())
-            .count()
-            .try_into()
-            .expect("Amount of neighbors should fit into u8.")
+    fn count_adjacent_mines(&self, coordinate: &Coordinate) -> u16 {
+        self.neighbor_coordinates(*coordinate)
+            .into_iter()
+            .filter_map(|neighbor| self.fields.get(neighbor))
+            .map(|field| u16::from(field.mines()))
+            .sum()
     }
 
     /// Count adjacent mines of all coordinates of the field.
-    fn count_all_adjacent_mines(&self) -> HashMap<Coordinate, u8> {
+    fn count_all_adjacent_mines(&self) -> HashMap<Coordinate, u16> {
         self.fields
             .enumerate()
             .map(|(coordinate, _)| (coordinate, self.count_adjacent_mines(&coordinate)))
@@ -161,51 +436,169 @@ impl Board {
     /// Mark initially visited field as visited, then populate mines and duds.
     ///
     /// This is to prevent stepping on a mine on first move, where we do not have any information about the grid yet.
-    fn first_move(&mut self, mines: u8, duds: u8, coordinate: Coordinate) -> MoveResult {
-        let result = self
-            .fields
-            .get_mut(coordinate)
-            .map_or(MoveResult::InvalidPosition, |field| {
-                field.visit();
-                MoveResult::Continue
-            });
+    fn first_move(&mut self, mines: usize, duds: usize, coordinate: Coordinate) -> MoveResult {
+        let Some(field) = self.fields.get_mut(coordinate) else {
+            self.init = Some((mines, duds));
+            return MoveResult::InvalidPosition;
+        };
+
+        field.visit();
+
+        if let Some(attempts) = self.no_guess {
+            if !self.initialize_without_guessing(mines, duds, coordinate, attempts) {
+                self.clear_mines();
+                if let Some(field) = self.fields.get_mut(coordinate) {
+                    field.remove(Field::VISITED);
+                }
 
-        if result == MoveResult::Continue {
-            self.initialize(mines, duds);
-            self.visit_neighbors(coordinate);
+                self.init = Some((mines, duds));
+                return MoveResult::Error(Error::NoGuessBoardNotFound);
+            }
+        } else {
+            self.initialize(mines, duds, &[]);
         }
 
-        result
+        self.visit_neighbors(coordinate);
+        MoveResult::Continue
     }
 
     /// Populate the field with mines and duds.
     ///
     /// We defer this after the first move to prevent stepping onto a mine on the first move.
-    fn initialize(&mut self, mines: u8, duds: u8) {
-        self.populate_mines(mines);
+    fn initialize(&mut self, mines: usize, duds: usize, excluded: &[Coordinate]) {
+        self.populate_mines(mines, excluded);
+        self.update_adjacent_mines();
+        self.populate_duds(duds);
+    }
+
+    /// Set the amount of adjacent mines on all fields.
+    fn update_adjacent_mines(&mut self) {
         let adjacent_mines = self.count_all_adjacent_mines();
         self.fields.enumerate_mut().for_each(|(coordinate, field)| {
             field.set_adjacent_mines(adjacent_mines.get(&coordinate).copied().unwrap_or(0));
         });
-        self.populate_duds(duds);
+    }
+
+    /// Populate the field with mines and duds, so that it can be solved from the given coordinate
+    /// without guessing.
+    ///
+    /// If there is enough space, the neighbors of the starting coordinate are kept free of mines.
+    ///
+    /// Return `false` if no such placement was found within the given amount of attempts.
+    fn initialize_without_guessing(
+        &mut self,
+        mines: usize,
+        duds: usize,
+        start: Coordinate,
+        attempts: NonZero<usize>,
+    ) -> bool {
+        let mut excluded = self.neighbor_coordinates(start);
+
+        if self.fields.size().get() - 1 - excluded.len() < mines {
+            excluded.clear();
+        }
+
+        for _ in 0..attempts.get() {
+            self.initialize(mines, duds, &excluded);
+
+            if self.is_solvable(start) {
+                return true;
+            }
+
+            self.clear_mines();
+        }
+
+        false
+    }
+
+    /// Remove all mines and duds from the field.
+    fn clear_mines(&mut self) {
+        for field in self.fields.iter_mut() {
+            field.remove(Field::MINES | Field::IS_DUD | Field::ADJACENT_MINES);
+        }
+    }
+
+    /// Return `true` if a logical solver can clear the field from the given coordinate on.
+    fn is_solvable(&self, start: Coordinate) -> bool {
+        let mut fields = self.fields.clone();
+
+        for field in fields.iter_mut() {
+            field.remove(Field::FLAGS);
+        }
+
+        visit_safe_neighbors(&mut fields, self.topology, self.neighborhood, start);
+        let mut mines = BTreeSet::new();
+
+        loop {
+            let mut cells = Grid::new_default(fields.width(), fields.height());
+            cells
+                .iter_mut()
+                .zip(fields.enumerate())
+                .for_each(|(cell, (coordinate, field))| {
+                    if field.has_been_visited() {
+                        *cell = Cell::Clear {
+                            adjacent_mines: field.adjacent_mines(),
+                        };
+                    } else if mines.contains(&coordinate.into()) {
+                        *cell = Cell::Mine;
+                    }
+                });
+            let hints = Solver::from(cells)
+                .with_topology(self.topology)
+                .with_neighborhood(self.neighborhood)
+                .hints();
+
+            if hints.is_empty() {
+                break;
+            }
+
+            for hint in hints {
+                if hint.is_safe() {
+                    visit_safe_neighbors(
+                        &mut fields,
+                        self.topology,
+                        self.neighborhood,
+                        hint.coordinate(),
+                    );
+                } else {
+                    mines.insert(<(usize, usize)>::from(hint.coordinate()));
+                }
+            }
+        }
+
+        fields
+            .iter()
+            .filter(|field| !field.has_mine())
+            .all(|field| field.has_been_visited())
     }
 
     /// Populate the field with mines.
-    fn populate_mines(&mut self, mines: u8) {
-        self.fields
-            .iter_mut()
-            .filter(|field| !field.has_been_visited())
-            .sample(&mut self.rng, mines.into())
-            .into_iter()
-            .for_each(Field::set_mine);
+    ///
+    /// Each field receives up to the maximum amount of mines per field.
+    /// Visited fields and the excluded coordinates are not populated.
+    fn populate_mines(&mut self, mines: usize, excluded: &[Coordinate]) {
+        let mines_per_field = usize::from(self.mines_per_field.get());
+
+        let slots = self
+            .fields
+            .enumerate()
+            .filter(|(coordinate, field)| {
+                !field.has_been_visited() && !excluded.contains(coordinate)
+            })
+            .flat_map(|(coordinate, _)| repeat_n(coordinate, mines_per_field));
+
+        for coordinate in self.rng.sample(slots, mines) {
+            if let Some(field) = self.fields.get_mut(coordinate) {
+                field.add_mine();
+            }
+        }
     }
 
     /// Populate the field with duds.
-    fn populate_duds(&mut self, duds: u8) {
-        self.fields
-            .iter_mut()
-            .filter(|field| field.has_mine())
-            .sample(&mut self.rng, duds.into())
+    fn populate_duds(&mut self, duds: usize) {
+        let mines = self.fields.iter_mut().filter(|field| field.has_mine());
+        self.rng
+            .sample(mines, duds)
             .into_iter()
             .for_each(Field::set_dud);
     }
@@ -219,9 +612,14 @@ impl Board {
         };
 
         match field.visit() {
-            VisitResult::SteppedOnMine => MoveResult::Lost,
+            VisitResult::SteppedOnMine if self.lives() == 0 => MoveResult::Lost,
+            VisitResult::SteppedOnMine => MoveResult::LifeLost(coordinate),
             VisitResult::AlreadyVisited | VisitResult::Flagged => MoveResult::Continue,
-            _ => {
+            VisitResult::SteppedOnDud => {
+                self.visit_neighbors(coordinate);
+                MoveResult::DudTriggered(coordinate)
+            }
+            VisitResult::Cleared => {
                 self.visit_neighbors(coordinate);
                 MoveResult::Continue
             }
@@ -232,12 +630,23 @@ impl Board {
     ///
     /// We do this for convenience, to uncover all adjacent fields that do not contain a mine.
     fn visit_neighbors(&mut self, coordinate: Coordinate) {
-        let mut iterator = SafeNeighbors::new(&mut self.fields, coordinate);
+        visit_safe_neighbors(
+            &mut self.fields,
+            self.topology,
+            self.neighborhood,
+            coordinate,
+        );
+    }
 
-        while let Some(field) = iterator.next() {
-            if !field.is_flagged() {
-                field.insert(Field::VISITED);
+    /// Check whether the game has been won by a move, which neither was invalid nor lost.
+    fn conclude(&self, result: MoveResult) -> MoveResult {
+        match result {
+            MoveResult::Continue | MoveResult::DudTriggered(_) | MoveResult::LifeLost(_)
+                if self.all_mines_cleared() =>
+            {
+                MoveResult::Won
             }
+            result => result,
         }
     }
 
@@ -252,9 +661,37 @@ impl Board {
     }
 }
 
-impl Display for Board {
-    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
-        writeln!(f, "{}", Header::new(self.fields.width().get()))?;
+/// Visit the field at the given coordinate and its neighbors, if it is safe to do so.
+fn visit_safe_neighbors(
+    fields: &mut Grid<Field>,
+    topology: Topology,
+    neighborhood: Neighborhood,
+    coordinate: Coordinate,
+) {
+    let mut iterator = SafeNeighbors::new(fields, topology, neighborhood, coordinate);
+
+    while let Some(field) = iterator.next() {
+        if !field.is_flagged() {
+            field.uncover();
+        }
+    }
+}
+
+impl Board {
+    /// Write the board with a header, displaying each field with the given view.
+    ///
+    /// On boards with hexagonal fields, odd rows are shifted to the right by half a field.
+    ///
+    /// # Errors
+    ///
+    /// Return an error if writing to the formatter fails.
+    pub fn fmt_with(
+        &self,
+        f: &mut Formatter<'_>,
+        view: impl Fn(Field) -> View,
+    ) -> std::fmt::Result {
+        let hexagonal = self.neighborhood.is_hexagonal();
+        writeln!(f, "{}", Header::new(self.fields.width().get(), hexagonal))?;
 
         let max_column = self.fields.width().get().saturating_sub(1);
         let max_row = self.fields.height().get().saturating_sub(1);
@@ -262,8 +699,12 @@ impl Display for Board {
         for (y, row) in self.fields.rows().enumerate() {
             write!(f, "{y:x}│")?;
 
-            for (x, field) in row.enumerate() {
-                field.fmt(f)?;
+            if hexagonal && y % 2 == 1 {
+                write!(f, " ")?;
+            }
+
+            for (x, &field) in row.enumerate() {
+                view(field).fmt(f)?;
 
                 if x < max_column {
                     write!(f, " ")?;
@@ -278,3 +719,10 @@ impl Display for Board {
         Ok(())
     }
 }
+
+impl Display for Board {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let game_over = f.alternate();
+        self.fmt_with(f, |field| field.view(game_over))
+    }
+}
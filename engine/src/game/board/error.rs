@@ -1,22 +1,111 @@
 use std::fmt::{Display, Formatter};
 
-/// Possible errors when constructing a game board (aka. minefield):
-#[derive(Debug)]
+use crate::Config;
+
+/// Possible errors when constructing a game board (aka. minefield) or playing on it.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum Error {
     /// The field is too large.
     FieldTooLarge,
+    /// The width or height exceeds [`Config::MAX_DIMENSION`](crate::Config::MAX_DIMENSION).
+    DimensionTooLarge {
+        /// The requested width.
+        width: usize,
+        /// The requested height.
+        height: usize,
+    },
     /// Too many mines were requested for the size of field.
-    TooManyMines,
+    ///
+    /// At least one field must remain free of mines.
+    TooManyMines {
+        /// The requested amount of mines.
+        mines: usize,
+        /// The amount of fields on the board.
+        fields: usize,
+    },
+    /// Too many mines per field were requested.
+    TooManyMinesPerField {
+        /// The requested amount of mines per field.
+        mines_per_field: u8,
+    },
     /// Too many duds were requested for the amount of mines.
-    TooManyDuds,
+    TooManyDuds {
+        /// The requested amount of duds.
+        duds: usize,
+        /// The requested amount of mines.
+        mines: usize,
+    },
+    /// Too many duds were requested for the fields holding mines.
+    ///
+    /// Duds are whole fields, and with multiple mines per field,
+    /// the mines may be placed on as few fields as can hold them.
+    TooManyDudsForMinedFields {
+        /// The requested amount of duds.
+        duds: usize,
+        /// The amount of fields which are guaranteed to hold mines.
+        mined_fields: usize,
+    },
+    /// The neighborhood has too many neighbors to count their mines,
+    /// given the maximum amount of mines per field.
+    NeighborhoodTooLarge {
+        /// The amount of neighbors of the neighborhood.
+        neighbors: usize,
+    },
+    /// Hexagonal fields on a torus require an even height,
+    /// so that the shift of the rows continues across the edges.
+    OddHexagonalTorus {
+        /// The requested height.
+        height: usize,
+    },
+    /// Boards without guessing cannot be generated with multiple mines per field.
+    NoGuessWithMultipleMinesPerField,
+    /// No placement of mines could be found, which allows to solve the board without guessing.
+    NoGuessBoardNotFound,
+    /// A replayed event resulted in a different result than recorded.
+    ReplayDiverged {
+        /// The index of the diverging event.
+        step: usize,
+    },
 }
 
 impl Display for Error {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         match self {
             Self::FieldTooLarge => write!(f, "field too large"),
-            Self::TooManyMines => write!(f, "too many mines for field size"),
-            Self::TooManyDuds => write!(f, "more duds than mines"),
+            Self::DimensionTooLarge { width, height } => write!(
+                f,
+                "field dimensions ({width}x{height}) exceed {} fields",
+                Config::MAX_DIMENSION
+            ),
+            Self::TooManyMines { mines, fields } => {
+                write!(f, "too many mines ({mines}) for field size ({fields})")
+            }
+            Self::TooManyMinesPerField { mines_per_field } => {
+                write!(f, "too many mines per field ({mines_per_field})")
+            }
+            Self::TooManyDuds { duds, mines } => {
+                write!(f, "more duds ({duds}) than mines ({mines})")
+            }
+            Self::TooManyDudsForMinedFields { duds, mined_fields } => {
+                write!(
+                    f,
+                    "more duds ({duds}) than guaranteed mined fields ({mined_fields})"
+                )
+            }
+            Self::NeighborhoodTooLarge { neighbors } => {
+                write!(f, "neighborhood too large ({neighbors} neighbors)")
+            }
+            Self::OddHexagonalTorus { height } => {
+                write!(f, "hexagonal torus with odd height ({height})")
+            }
+            Self::NoGuessWithMultipleMinesPerField => {
+                write!(f, "no-guess boards require a single mine per field")
+            }
+            Self::NoGuessBoardNotFound => {
+                write!(f, "no board found that can be solved without guessing")
+            }
+            Self::ReplayDiverged { step } => write!(f, "replay diverged at step {step}"),
         }
     }
 }
@@ -10,23 +10,39 @@ mod visit_result;
 
 /// A field on the game board (aka. minefield).
 #[derive(Copy, Clone, Debug, Default, Eq, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(transparent)
+)]
 #[repr(transparent)]
-pub struct Field(u8);
+pub struct Field(u32);
+
+/// Position of the amount of mines on the field.
+const MINES_SHIFT: u32 = 12;
+
+/// Position of the amount of flags on the field.
+const FLAGS_SHIFT: u32 = 16;
 
 bitflags! {
-    impl Field: u8 {
-        /// Mask for the field's flags.
-        const FLAGS = 0b1111_0000;
+    impl Field: u32 {
+        /// Mask for the field's state, i.e. everything but the amount of its adjacent mines.
+        const STATE = 0xFFFF_F000;
         /// Mask for the amount of the field's adjacent mines.
-        const ADJACENT_MINES = 0b0000_1111;
-        /// If this flag is set, the field is considered to have a mine.
-        const MINED = 0b0001_0000;
+        ///
+        /// This holds up to 4095 adjacent mines, as needed by large neighborhoods
+        /// with multiple mines per field.
+        const ADJACENT_MINES = 0x0000_0FFF;
+        /// Mask for the amount of mines on the field, up to 15.
+        const MINES = 0x0000_F000;
+        /// Mask for the amount of flags on the field, up to 15.
+        const FLAGS = 0x000F_0000;
         /// If this flag is set, the field is considered to have been visited.
-        const VISITED = 0b0010_0000;
-        /// If this flag is set, the field is considered to have a flag on it.
-        const FLAGGED = 0b0100_0000;
+        const VISITED = 0x0010_0000;
         /// If this flag is set, the field is considered to be a dud.
-        const IS_DUD = 0b1000_0000;
+        const IS_DUD = 0x0020_0000;
+        /// If this flag is set, the field is marked with a question mark.
+        const QUESTIONED = 0x0040_0000;
     }
 }
 
@@ -34,7 +50,23 @@ impl Field {
     /// Return `true` if the field contains a mine.
     #[must_use]
     pub const fn has_mine(self) -> bool {
-        self.contains(Self::MINED)
+        self.intersects(Self::MINES)
+    }
+
+    /// Return the amount of mines on the field.
+    #[must_use]
+    #[expect(clippy::cast_possible_truncation)]
+    pub const fn mines(self) -> u8 {
+        // The mask only covers four bits.
+        (self.intersection(Self::MINES).0 >> MINES_SHIFT) as u8
+    }
+
+    /// Return the amount of flags on the field.
+    #[must_use]
+    #[expect(clippy::cast_possible_truncation)]
+    pub const fn flags(self) -> u8 {
+        // The mask only covers four bits.
+        (self.intersection(Self::FLAGS).0 >> FLAGS_SHIFT) as u8
     }
 
     /// Return `true` if the field has been visited.
@@ -46,7 +78,13 @@ impl Field {
     /// Return `true` if the field has been flagged.
     #[must_use]
     pub const fn is_flagged(self) -> bool {
-        self.contains(Self::FLAGGED)
+        self.intersects(Self::FLAGS)
+    }
+
+    /// Return `true` if the field is marked with a question mark.
+    #[must_use]
+    pub const fn is_questioned(self) -> bool {
+        self.contains(Self::QUESTIONED)
     }
 
     /// Return `true` if the field is a dud.
@@ -57,13 +95,29 @@ impl Field {
 
     /// Return the amount of mines adjacent to the field.
     #[must_use]
-    pub const fn adjacent_mines(self) -> u8 {
-        self.intersection(Self::ADJACENT_MINES).0
+    #[expect(clippy::cast_possible_truncation)]
+    pub const fn adjacent_mines(self) -> u16 {
+        // The mask only covers twelve bits.
+        self.intersection(Self::ADJACENT_MINES).0 as u16
+    }
+
+    /// Set the field to contain a single mine.
+    pub const fn set_mine(&mut self) {
+        self.set_mines(1);
     }
 
-    /// Set the field to contain a mine.
-    pub fn set_mine(&mut self) {
-        self.insert(Self::MINED);
+    /// Add a mine to the field.
+    pub const fn add_mine(&mut self) {
+        self.set_mines(self.mines().saturating_add(1));
+    }
+
+    /// Set the amount of mines on the field.
+    ///
+    /// Amounts above 15 are truncated.
+    pub const fn set_mines(&mut self, mines: u8) {
+        *self = self
+            .difference(Self::MINES)
+            .union(Self::MINES.intersection(Self((mines as u32) << MINES_SHIFT)));
     }
 
     /// Set the field to be a dud.
@@ -72,10 +126,10 @@ impl Field {
     }
 
     /// Set the field's amount of adjacent mines.
-    pub const fn set_adjacent_mines(&mut self, adjacent_mines: u8) {
+    pub const fn set_adjacent_mines(&mut self, adjacent_mines: u16) {
         *self = self
-            .intersection(Self::FLAGS)
-            .union(Self::ADJACENT_MINES.intersection(Self(adjacent_mines)));
+            .intersection(Self::STATE)
+            .union(Self::ADJACENT_MINES.intersection(Self(adjacent_mines as u32)));
     }
 
     /// Visit the field.
@@ -88,7 +142,7 @@ impl Field {
             return VisitResult::AlreadyVisited;
         }
 
-        self.insert(Self::VISITED);
+        self.uncover();
 
         if !self.has_mine() {
             return VisitResult::Cleared;
@@ -101,13 +155,61 @@ impl Field {
         VisitResult::SteppedOnMine
     }
 
+    /// Mark the field as visited, removing its question mark.
+    ///
+    /// Unlike [`Self::visit`], this does not check whether the field is flagged or mined.
+    pub fn uncover(&mut self) {
+        self.remove(Self::QUESTIONED);
+        self.insert(Self::VISITED);
+    }
+
     /// Toggle the flag on the field.
-    pub fn toggle_flag(&mut self) {
+    ///
+    /// This removes all flags from a flagged field or puts a single flag onto an unflagged one.
+    pub const fn toggle_flag(&mut self) {
         if !self.has_been_visited() {
-            self.toggle(Self::FLAGGED);
+            self.set_flags(if self.is_flagged() { 0 } else { 1 });
         }
     }
 
+    /// Put another flag onto the field, unless it already carries the given maximum of flags.
+    pub const fn raise_flag(&mut self, max: u8) {
+        if !self.has_been_visited() && self.flags() < max {
+            self.set_flags(self.flags() + 1);
+        }
+    }
+
+    /// Toggle the question mark on the field.
+    ///
+    /// Marking a flagged field removes its flags.
+    pub const fn toggle_mark(&mut self) {
+        if !self.has_been_visited() {
+            if self.is_questioned() {
+                *self = self.difference(Self::QUESTIONED);
+            } else {
+                self.set_flags(0);
+                *self = self.union(Self::QUESTIONED);
+            }
+        }
+    }
+
+    /// Remove a flag from the field, if any.
+    pub const fn lower_flag(&mut self) {
+        if !self.has_been_visited() && self.is_flagged() {
+            self.set_flags(self.flags() - 1);
+        }
+    }
+
+    /// Set the amount of flags on the field, which removes its question mark.
+    ///
+    /// Amounts above 15 are truncated.
+    const fn set_flags(&mut self, flags: u8) {
+        *self = self
+            .difference(Self::FLAGS)
+            .difference(Self::QUESTIONED)
+            .union(Self::FLAGS.intersection(Self((flags as u32) << FLAGS_SHIFT)));
+    }
+
     /// Return the expected view of the field.
     #[must_use]
     pub const fn view(self, game_over: bool) -> View {
@@ -118,13 +220,25 @@ impl Field {
             self.has_mine(),
             self.is_dud(),
         ) {
-            (false, false, true, _, _) | (true, false, true, true, _) => View::Flag,
+            // At the end of the game, flags are only kept if they match the amount of mines.
+            (false, false, true, _, _) | (true, false, true, true, _)
+                if !game_over || self.flags() == self.mines() =>
+            {
+                match self.flags() {
+                    1 => View::Flag,
+                    flags => View::Flags(flags),
+                }
+            }
             (_, true, _, true, true) => View::SteppedOnDud,
             (_, true, _, true, false) => View::SteppedOnMine,
+            (false, false, false, _, _) if self.is_questioned() => View::Questioned,
             (false, true, false, false, _) | (true, _, _, false, _) => View::Clear {
                 adjacent_mines: self.adjacent_mines(),
             },
-            (true, false, false, true, _) => View::Mine,
+            (true, false, _, true, _) => match self.mines() {
+                1 => View::Mine,
+                mines => View::Mines(mines),
+            },
             _ => View::Covered,
         }
     }
@@ -2,12 +2,17 @@ use std::fmt::{Debug, Display, Formatter};
 
 /// View state of a field.
 #[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum View {
     /// The field has not been visited yet and is covered.
     #[default]
     Covered,
     /// The field is flagged.
     Flag,
+    /// The field carries the given amount of flags, which is more than one.
+    Flags(u8),
+    /// The field is marked with a question mark.
+    Questioned,
     /// The player stepped onto a dud.
     SteppedOnDud,
     /// The player stepped onto a live mine.
@@ -15,31 +20,50 @@ pub enum View {
     /// The field is clear.
     Clear {
         /// The amount of mines adjacent to the field.
-        adjacent_mines: u8,
+        adjacent_mines: u16,
     },
     /// The field contains a mine.
     Mine,
+    /// The field contains the given amount of mines, which is more than one.
+    Mines(u8),
 }
 
 impl View {
     /// Returns a char representation of the field's view.
+    ///
+    /// Amounts of adjacent mines above nine are represented by the letters `a` to `z`
+    /// and larger amounts by `+`.
     #[must_use]
     fn as_char(self) -> char {
         match self {
             Self::Covered => '■',
             Self::Flag => '⚐',
+            Self::Flags(flags) => circled(flags, '\u{2460}', '\u{246A}').unwrap_or('⚐'),
+            Self::Questioned => '?',
             Self::SteppedOnDud => '~',
             Self::SteppedOnMine => '☠',
             Self::Clear { adjacent_mines } => match adjacent_mines {
                 0 => ' ',
-                mines => char::from_digit(mines.into(), 10)
-                    .expect("Amount of adjacent mines should be a single decimal digit."),
+                mines => char::from_digit(mines.into(), 36).unwrap_or('+'),
             },
             Self::Mine => '*',
+            Self::Mines(mines) => circled(mines, '\u{2776}', '\u{24EB}').unwrap_or('*'),
         }
     }
 }
 
+/// Return the circled number from one to fifteen, i.e. the amount of flags or mines on a field.
+///
+/// Since Unicode does not provide all numbers in a contiguous block, the numbers from one to ten
+/// start at `one` and those from eleven to fifteen at `eleven`.
+fn circled(number: u8, one: char, eleven: char) -> Option<char> {
+    match number {
+        1..=10 => char::from_u32(u32::from(one) + u32::from(number) - 1),
+        11..=15 => char::from_u32(u32::from(eleven) + u32::from(number) - 11),
+        _ => None,
+    }
+}
+
 impl Display for View {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         Display::fmt(&self.as_char(), f)
@@ -4,12 +4,15 @@ use std::fmt::{Display, Formatter};
 #[derive(Debug)]
 pub struct Header {
     width: usize,
+    shifted: bool,
 }
 
 impl Header {
+    /// Create a new header for a board of the given width,
+    /// whose odd rows may be shifted by half a field.
     #[must_use]
-    pub const fn new(width: usize) -> Self {
-        Self { width }
+    pub const fn new(width: usize, shifted: bool) -> Self {
+        Self { width, shifted }
     }
 }
 
@@ -28,7 +31,7 @@ impl Display for Header {
 
         write!(f, "\n─┼")?;
 
-        for _ in 0..=max_column.saturating_mul(2) {
+        for _ in 0..=max_column.saturating_mul(2) + usize::from(self.shifted) {
             write!(f, "─")?;
         }
 
@@ -1,27 +1,26 @@
-use std::time::Instant;
+use grid2d::Coordinate;
 
-use crate::{Outcome, State};
+use crate::Error;
 
 /// Possible outcomes of a player's move on the grid.
-#[derive(Debug, PartialEq, Eq)]
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum MoveResult {
     /// The game may continue.
     Continue,
+    /// The player stepped onto a dud at the given coordinate and the game may continue.
+    DudTriggered(
+        #[cfg_attr(feature = "serde", serde(with = "crate::game::coordinate"))] Coordinate,
+    ),
+    /// The player stepped onto a mine at the given coordinate, which cost a life,
+    /// and the game may continue.
+    LifeLost(#[cfg_attr(feature = "serde", serde(with = "crate::game::coordinate"))] Coordinate),
     /// The given coordinate was invalid, e.g. not on the grid.
     InvalidPosition,
     /// The game was lost.
     Lost,
     /// The game was won.
     Won,
-}
-
-impl From<MoveResult> for State {
-    fn from(result: MoveResult) -> Self {
-        match result {
-            MoveResult::InvalidPosition => Self::InvalidMove,
-            MoveResult::Continue => Self::Continue,
-            MoveResult::Lost => Self::GameOver(Outcome::Lost(Instant::now())),
-            MoveResult::Won => Self::GameOver(Outcome::Won(Instant::now())),
-        }
-    }
+    /// The move could not be made due to an error.
+    Error(Error),
 }
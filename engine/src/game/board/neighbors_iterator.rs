@@ -3,21 +3,33 @@ use std::collections::BTreeSet;
 use grid2d::{Coordinate, Grid};
 
 use crate::game::board::field::Field;
+use crate::game::neighborhood::Neighborhood;
+use crate::game::topology::Topology;
 
 /// An iterator over neighbors of a coordinate, which are deemed safe to uncover.
 #[derive(Debug, Eq, PartialEq)]
 pub struct SafeNeighbors<'grid> {
     fields: &'grid mut Grid<Field>,
+    topology: Topology,
+    neighborhood: Neighborhood,
     starting_points: BTreeSet<(usize, usize)>,
     processed: BTreeSet<(usize, usize)>,
 }
 
 impl<'grid> SafeNeighbors<'grid> {
-    /// Create a new [`SafeNeighbors`] iterator from the given [`Grid`] and starting coordinate.
+    /// Create a new [`SafeNeighbors`] iterator from the given [`Grid`], its topology,
+    /// the neighborhood of its fields and starting coordinate.
     #[must_use]
-    pub fn new(fields: &'grid mut Grid<Field>, start: Coordinate) -> Self {
+    pub fn new(
+        fields: &'grid mut Grid<Field>,
+        topology: Topology,
+        neighborhood: Neighborhood,
+        start: Coordinate,
+    ) -> Self {
         Self {
             fields,
+            topology,
+            neighborhood,
             starting_points: BTreeSet::from([start.into()]),
             processed: BTreeSet::new(),
         }
@@ -30,9 +42,15 @@ impl<'grid> SafeNeighbors<'grid> {
 
         if self.fields.get(starting_point)?.adjacent_mines() == 0 {
             self.starting_points.extend(
-                self.fields
-                    .neighbors(starting_point)
-                    .map(|(coordinate, _)| coordinate.into())
+                self.topology
+                    .neighbors(
+                        self.neighborhood,
+                        self.fields.width(),
+                        self.fields.height(),
+                        starting_point.into(),
+                    )
+                    .into_iter()
+                    .map(<(usize, usize)>::from)
                     .filter(|starting_point| !self.processed.contains(starting_point)),
             );
         }
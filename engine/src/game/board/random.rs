@@ -0,0 +1,64 @@
+use chacha20::ChaCha8Rng;
+use chacha20::rand_core::{Rng, SeedableRng};
+
+/// Portable source of randomness for placing mines and duds.
+///
+/// Both the generator and the sampling are fully specified here, so that the same seed results
+/// in the same placement of mines and duds on every platform and with every release.
+#[derive(Debug)]
+pub struct Random(ChaCha8Rng);
+
+impl Random {
+    /// Create a new source of randomness from the given seed.
+    ///
+    /// The seed's little-endian bytes form the start of the generator's key,
+    /// while the remaining bytes are zero.
+    #[must_use]
+    pub fn new(seed: u64) -> Self {
+        let mut key = [0; 32];
+        key[..8].copy_from_slice(&seed.to_le_bytes());
+        Self(ChaCha8Rng::from_seed(key))
+    }
+
+    /// Randomly choose the given amount of items.
+    ///
+    /// This performs a partial Fisher-Yates shuffle, so that every selection is equally likely.
+    /// If there are fewer items than requested, all items are returned.
+    pub fn sample<T>(&mut self, items: impl IntoIterator<Item = T>, amount: usize) -> Vec<T> {
+        let mut items: Vec<_> = items.into_iter().collect();
+        let amount = amount.min(items.len());
+
+        for index in 0..amount {
+            let other = index + self.index(items.len() - index);
+            items.swap(index, other);
+        }
+
+        items.truncate(amount);
+        items
+    }
+
+    /// Return a uniformly distributed index below the given non-zero bound.
+    ///
+    /// Values of the generator, which would favor lower indices, are rejected.
+    #[expect(clippy::cast_possible_truncation)]
+    fn index(&mut self, bound: usize) -> usize {
+        // The bound is at most the length of a vector and thus fits into 64 bits.
+        let bound = bound as u64;
+        let threshold = bound.wrapping_neg() % bound;
+
+        loop {
+            let value = self.0.next_u64();
+
+            if value >= threshold {
+                // The result is below the bound and thus fits into an index.
+                return (value % bound) as usize;
+            }
+        }
+    }
+}
+
+impl Clone for Random {
+    fn clone(&self) -> Self {
+        Self(ChaCha8Rng::deserialize_state(&self.0.serialize_state()))
+    }
+}
@@ -0,0 +1,61 @@
+use std::num::NonZero;
+
+use grid2d::{FromIterableError, Grid};
+use serde::{Deserialize, Serialize};
+
+use crate::game::board::Board;
+use crate::game::board::field::Field;
+use crate::game::board::random::Random;
+use crate::game::neighborhood::Neighborhood;
+use crate::game::topology::Topology;
+
+/// Serializable representation of a [`Board`].
+///
+/// The random number generator is not stored, but re-created from the seed.
+/// This is sufficient, since it is only used to place mines and duds on the first move.
+#[derive(Debug, Deserialize, Serialize)]
+pub struct Snapshot {
+    width: NonZero<usize>,
+    fields: Vec<Field>,
+    init: Option<(usize, usize)>,
+    seed: u64,
+    no_guess: Option<NonZero<usize>>,
+    lives: NonZero<usize>,
+    topology: Topology,
+    neighborhood: Neighborhood,
+    mines_per_field: NonZero<u8>,
+}
+
+impl From<Board> for Snapshot {
+    fn from(board: Board) -> Self {
+        Self {
+            width: board.fields.width(),
+            fields: board.fields.iter().copied().collect(),
+            init: board.init,
+            seed: board.seed,
+            no_guess: board.no_guess,
+            lives: board.lives,
+            topology: board.topology,
+            neighborhood: board.neighborhood,
+            mines_per_field: board.mines_per_field,
+        }
+    }
+}
+
+impl TryFrom<Snapshot> for Board {
+    type Error = FromIterableError;
+
+    fn try_from(snapshot: Snapshot) -> Result<Self, Self::Error> {
+        Grid::try_from((snapshot.fields, snapshot.width)).map(|fields| Self {
+            fields,
+            init: snapshot.init,
+            seed: snapshot.seed,
+            rng: Random::new(snapshot.seed),
+            no_guess: snapshot.no_guess,
+            lives: snapshot.lives,
+            topology: snapshot.topology,
+            neighborhood: snapshot.neighborhood,
+            mines_per_field: snapshot.mines_per_field,
+        })
+    }
+}
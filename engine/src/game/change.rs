@@ -0,0 +1,32 @@
+use grid2d::Coordinate;
+
+use crate::View;
+
+/// A change of a field's view caused by a move.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Change {
+    #[cfg_attr(feature = "serde", serde(with = "crate::game::coordinate"))]
+    coordinate: Coordinate,
+    view: View,
+}
+
+impl Change {
+    /// Create a new change.
+    #[must_use]
+    pub const fn new(coordinate: Coordinate, view: View) -> Self {
+        Self { coordinate, view }
+    }
+
+    /// Return the coordinate of the changed field.
+    #[must_use]
+    pub const fn coordinate(&self) -> Coordinate {
+        self.coordinate
+    }
+
+    /// Return the new view of the field.
+    #[must_use]
+    pub const fn view(&self) -> View {
+        self.view
+    }
+}
@@ -0,0 +1,18 @@
+use std::time::{Instant, SystemTime};
+
+pub use self::manual::ManualClock;
+pub use self::system::SystemClock;
+
+mod manual;
+mod system;
+
+/// A source of the current time.
+///
+/// All times of a game, e.g. its start and the time of its outcome, are taken from its clock.
+pub trait Clock {
+    /// Return the current instant of the monotonic clock.
+    fn now(&self) -> Instant;
+
+    /// Return the current wall-clock time.
+    fn system_time(&self) -> SystemTime;
+}
@@ -0,0 +1,44 @@
+use std::time::{Duration, Instant, SystemTime};
+
+use crate::game::clock::Clock;
+
+/// A clock, which only advances when told to, e.g. for tests and replays.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct ManualClock {
+    instant: Instant,
+    system_time: SystemTime,
+}
+
+impl ManualClock {
+    /// Create a new manual clock, which starts at the given times.
+    #[must_use]
+    pub const fn new(instant: Instant, system_time: SystemTime) -> Self {
+        Self {
+            instant,
+            system_time,
+        }
+    }
+
+    /// Advance the clock by the given duration.
+    pub fn advance(&mut self, duration: Duration) {
+        self.instant += duration;
+        self.system_time += duration;
+    }
+}
+
+impl Default for ManualClock {
+    /// Create a new manual clock, which starts at the current system time.
+    fn default() -> Self {
+        Self::new(Instant::now(), SystemTime::now())
+    }
+}
+
+impl Clock for ManualClock {
+    fn now(&self) -> Instant {
+        self.instant
+    }
+
+    fn system_time(&self) -> SystemTime {
+        self.system_time
+    }
+}
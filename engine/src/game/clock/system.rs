@@ -0,0 +1,17 @@
+use std::time::{Instant, SystemTime};
+
+use crate::game::clock::Clock;
+
+/// A clock based on the system's clocks.
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+pub struct SystemClock;
+
+impl Clock for SystemClock {
+    fn now(&self) -> Instant {
+        Instant::now()
+    }
+
+    fn system_time(&self) -> SystemTime {
+        SystemTime::now()
+    }
+}
@@ -0,0 +1,234 @@
+use std::num::NonZero;
+use std::time::Duration;
+
+use crate::game::layout::Layout;
+use crate::game::neighborhood::Neighborhood;
+use crate::game::topology::Topology;
+
+/// Configuration of a new game.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Config {
+    width: NonZero<usize>,
+    height: NonZero<usize>,
+    mines: usize,
+    duds: usize,
+    seed: Option<u64>,
+    undo: bool,
+    no_guess: Option<NonZero<usize>>,
+    time_limit: Option<Duration>,
+    move_limit: Option<NonZero<usize>>,
+    lives: NonZero<usize>,
+    topology: Topology,
+    neighborhood: Neighborhood,
+    mines_per_field: NonZero<u8>,
+}
+
+impl Config {
+    /// The maximum width and height of a board.
+    pub const MAX_DIMENSION: usize = 255;
+
+    /// Create a new game configuration without duds, with a random seed, undo enabled and a single life.
+    #[must_use]
+    pub const fn new(width: NonZero<usize>, height: NonZero<usize>, mines: usize) -> Self {
+        Self {
+            width,
+            height,
+            mines,
+            duds: 0,
+            seed: None,
+            undo: true,
+            no_guess: None,
+            time_limit: None,
+            move_limit: None,
+            lives: NonZero::<usize>::MIN,
+            topology: Topology::Flat,
+            neighborhood: Neighborhood::Moore,
+            mines_per_field: NonZero::<u8>::MIN,
+        }
+    }
+
+    /// Set the amount of duds among the mines.
+    ///
+    /// Duds are whole fields, so with multiple mines per field, there may be no more duds
+    /// than the fewest fields which can hold all mines.
+    #[must_use]
+    pub const fn with_duds(mut self, duds: usize) -> Self {
+        self.duds = duds;
+        self
+    }
+
+    /// Set the seed for placing mines and duds.
+    ///
+    /// The same seed and the same first move always result in the same placement of mines and duds.
+    /// Seeds are portable, i.e. they result in the same placement on every platform,
+    /// so that they can be shared between players and tools.
+    #[must_use]
+    pub const fn with_seed(mut self, seed: u64) -> Self {
+        self.seed = Some(seed);
+        self
+    }
+
+    /// Enable or disable undoing and redoing moves, e.g. to disable them for ranked play.
+    #[must_use]
+    pub const fn with_undo(mut self, undo: bool) -> Self {
+        self.undo = undo;
+        self
+    }
+
+    /// Only place mines in a way that the board can be solved without guessing.
+    ///
+    /// On the first move, the placement of mines is repeated up to the given amount of `attempts`,
+    /// until a logical solver can clear the board from the first move on.
+    /// If no such placement is found, the first move is rejected with
+    /// [`Error::NoGuessBoardNotFound`](crate::Error::NoGuessBoardNotFound).
+    /// Retrying the first move continues with further placements, which may succeed.
+    #[must_use]
+    pub const fn with_no_guess(mut self, attempts: NonZero<usize>) -> Self {
+        self.no_guess = Some(attempts);
+        self
+    }
+
+    /// Set a time limit, after which the game ends with [`Outcome::TimedOut`](crate::Outcome::TimedOut).
+    ///
+    /// Pauses do not count towards the time limit.
+    #[must_use]
+    pub const fn with_time_limit(mut self, time_limit: Duration) -> Self {
+        self.time_limit = Some(time_limit);
+        self
+    }
+
+    /// Set a maximum amount of moves, after which the game ends with
+    /// [`Outcome::OutOfMoves`](crate::Outcome::OutOfMoves).
+    ///
+    /// Every visit, flag and chord which changes the board counts as a move,
+    /// while undoing a move does not give it back.
+    #[must_use]
+    pub const fn with_move_limit(mut self, move_limit: NonZero<usize>) -> Self {
+        self.move_limit = Some(move_limit);
+        self
+    }
+
+    /// Set the amount of lives.
+    ///
+    /// Stepping onto a mine, which is not a dud, costs a life and the game is only lost once all
+    /// lives are used up.
+    #[must_use]
+    pub const fn with_lives(mut self, lives: NonZero<usize>) -> Self {
+        self.lives = lives;
+        self
+    }
+
+    /// Set the topology of the game board, e.g. to let it wrap around at its edges.
+    #[must_use]
+    pub const fn with_topology(mut self, topology: Topology) -> Self {
+        self.topology = topology;
+        self
+    }
+
+    /// Set the neighborhood of the fields, e.g. to play on hexagonal fields
+    /// or with knight's-move neighbors.
+    ///
+    /// The neighborhood determines the adjacent mines, the fields uncovered by flood fill and
+    /// the fields visited by chords.
+    #[must_use]
+    pub const fn with_neighborhood(mut self, neighborhood: Neighborhood) -> Self {
+        self.neighborhood = neighborhood;
+        self
+    }
+
+    /// Set the maximum amount of mines a single field can hold, up to 15.
+    ///
+    /// The amount of mines of the configuration is the total of all mines on the board,
+    /// and the adjacent mines of a field sum up the mines on its neighbors.
+    /// Boards without guessing can only be generated with a single mine per field.
+    #[must_use]
+    pub const fn with_mines_per_field(mut self, mines_per_field: NonZero<u8>) -> Self {
+        self.mines_per_field = mines_per_field;
+        self
+    }
+
+    /// Return the width of the game board.
+    #[must_use]
+    pub const fn width(&self) -> NonZero<usize> {
+        self.width
+    }
+
+    /// Return the height of the game board.
+    #[must_use]
+    pub const fn height(&self) -> NonZero<usize> {
+        self.height
+    }
+
+    /// Return the amount of mines.
+    #[must_use]
+    pub const fn mines(&self) -> usize {
+        self.mines
+    }
+
+    /// Return the amount of duds among the mines.
+    #[must_use]
+    pub const fn duds(&self) -> usize {
+        self.duds
+    }
+
+    /// Return the seed, if one has been set.
+    #[must_use]
+    pub const fn seed(&self) -> Option<u64> {
+        self.seed
+    }
+
+    /// Return `true` if undoing and redoing moves is enabled.
+    #[must_use]
+    pub const fn undo(&self) -> bool {
+        self.undo
+    }
+
+    /// Return the amount of attempts to place mines without the need to guess, if enabled.
+    #[must_use]
+    pub const fn no_guess(&self) -> Option<NonZero<usize>> {
+        self.no_guess
+    }
+
+    /// Return the time limit, if one has been set.
+    #[must_use]
+    pub const fn time_limit(&self) -> Option<Duration> {
+        self.time_limit
+    }
+
+    /// Return the amount of lives.
+    #[must_use]
+    pub const fn lives(&self) -> NonZero<usize> {
+        self.lives
+    }
+
+    /// Return the topology of the game board.
+    #[must_use]
+    pub const fn topology(&self) -> Topology {
+        self.topology
+    }
+
+    /// Return the neighborhood of the fields.
+    #[must_use]
+    pub const fn neighborhood(&self) -> Neighborhood {
+        self.neighborhood
+    }
+
+    /// Return the maximum amount of mines a single field can hold.
+    #[must_use]
+    pub const fn mines_per_field(&self) -> NonZero<u8> {
+        self.mines_per_field
+    }
+
+    /// Return the maximum amount of moves, if one has been set.
+    #[must_use]
+    pub const fn move_limit(&self) -> Option<NonZero<usize>> {
+        self.move_limit
+    }
+}
+
+impl From<&Layout> for Config {
+    fn from(layout: &Layout) -> Self {
+        Self::new(layout.width(), layout.height(), layout.mines()).with_duds(layout.duds())
+    }
+}
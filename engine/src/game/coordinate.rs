@@ -0,0 +1,20 @@
+//! Serialization of [`Coordinate`]s as `(x, y)` tuples, since they do not implement serde's traits.
+
+use grid2d::Coordinate;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+/// Serialize a coordinate as an `(x, y)` tuple.
+pub fn serialize<S>(coordinate: &Coordinate, serializer: S) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+{
+    <(usize, usize)>::from(*coordinate).serialize(serializer)
+}
+
+/// Deserialize a coordinate from an `(x, y)` tuple.
+pub fn deserialize<'de, D>(deserializer: D) -> Result<Coordinate, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    <(usize, usize)>::deserialize(deserializer).map(Into::into)
+}
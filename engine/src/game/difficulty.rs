@@ -0,0 +1,69 @@
+use std::num::NonZero;
+
+use crate::game::config::Config;
+
+const NINE: NonZero<usize> = NonZero::new(9).expect("Nine should be non-zero.");
+const SIXTEEN: NonZero<usize> = NonZero::new(16).expect("Sixteen should be non-zero.");
+const THIRTY: NonZero<usize> = NonZero::new(30).expect("Thirty should be non-zero.");
+
+/// Difficulty of a game, determining the board size and the amount of mines.
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Difficulty {
+    /// A 9x9 board with 10 mines.
+    #[default]
+    Beginner,
+    /// A 16x16 board with 40 mines.
+    Intermediate,
+    /// A 30x16 board with 99 mines.
+    Expert,
+    /// A board with custom dimensions and amount of mines.
+    Custom {
+        /// The width of the game board.
+        width: NonZero<usize>,
+        /// The height of the game board.
+        height: NonZero<usize>,
+        /// The amount of mines.
+        mines: usize,
+    },
+}
+
+impl Difficulty {
+    /// Return the width of the game board.
+    #[must_use]
+    pub const fn width(self) -> NonZero<usize> {
+        match self {
+            Self::Beginner => NINE,
+            Self::Intermediate => SIXTEEN,
+            Self::Expert => THIRTY,
+            Self::Custom { width, .. } => width,
+        }
+    }
+
+    /// Return the height of the game board.
+    #[must_use]
+    pub const fn height(self) -> NonZero<usize> {
+        match self {
+            Self::Beginner => NINE,
+            Self::Intermediate | Self::Expert => SIXTEEN,
+            Self::Custom { height, .. } => height,
+        }
+    }
+
+    /// Return the amount of mines.
+    #[must_use]
+    pub const fn mines(self) -> usize {
+        match self {
+            Self::Beginner => 10,
+            Self::Intermediate => 40,
+            Self::Expert => 99,
+            Self::Custom { mines, .. } => mines,
+        }
+    }
+}
+
+impl From<Difficulty> for Config {
+    fn from(difficulty: Difficulty) -> Self {
+        Self::new(difficulty.width(), difficulty.height(), difficulty.mines())
+    }
+}
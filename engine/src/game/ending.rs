@@ -0,0 +1,43 @@
+use std::time::Instant;
+
+use crate::game::outcome::Outcome;
+
+/// The kind of an [`Outcome`] without the instant of when the game ended.
+///
+/// Unlike [`Instant`]s, this can be persisted and is thus used to store outcomes.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Ending {
+    /// See [`Outcome::Won`].
+    Won,
+    /// See [`Outcome::Lost`].
+    Lost,
+    /// See [`Outcome::TimedOut`].
+    TimedOut,
+    /// See [`Outcome::OutOfMoves`].
+    OutOfMoves,
+}
+
+impl Ending {
+    /// Return the outcome of this kind, which ended the game at the given instant.
+    #[must_use]
+    pub const fn at(self, end: Instant) -> Outcome {
+        match self {
+            Self::Won => Outcome::Won(end),
+            Self::Lost => Outcome::Lost(end),
+            Self::TimedOut => Outcome::TimedOut(end),
+            Self::OutOfMoves => Outcome::OutOfMoves(end),
+        }
+    }
+}
+
+impl From<Outcome> for Ending {
+    fn from(outcome: Outcome) -> Self {
+        match outcome {
+            Outcome::Won(_) => Self::Won,
+            Outcome::Lost(_) => Self::Lost,
+            Outcome::TimedOut(_) => Self::TimedOut,
+            Outcome::OutOfMoves(_) => Self::OutOfMoves,
+        }
+    }
+}
@@ -0,0 +1,132 @@
+use grid2d::{Coordinate, Grid};
+
+use crate::game::action::Action;
+use crate::game::board::Board;
+use crate::game::board::field::Field;
+use crate::game::ending::Ending;
+use crate::game::outcome::Outcome;
+
+/// The history of moves applied to a game, which can be undone and redone.
+#[derive(Clone, Debug, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct History {
+    moves: Vec<Move>,
+    position: usize,
+}
+
+impl History {
+    /// Record a move.
+    ///
+    /// This discards all moves that have been undone before.
+    pub fn record(&mut self, record: Move) {
+        self.moves.truncate(self.position);
+        self.moves.push(record);
+        self.position = self.moves.len();
+    }
+
+    /// Step back and return the move to undo, if any.
+    pub fn undo(&mut self) -> Option<&Move> {
+        self.position = self.position.checked_sub(1)?;
+        self.moves.get(self.position)
+    }
+
+    /// Step forward and return the move to redo, if any.
+    pub fn redo(&mut self) -> Option<&Move> {
+        let record = self.moves.get(self.position)?;
+        self.position += 1;
+        Some(record)
+    }
+
+    /// Return an iterator over the actions of all moves that are currently applied.
+    pub fn actions(&self) -> impl Iterator<Item = Action> {
+        self.moves[..self.position]
+            .iter()
+            .map(|record| record.action)
+    }
+}
+
+/// A move applied to the game and the changes it made.
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Move {
+    action: Action,
+    changes: Vec<Change>,
+    init_before: Option<(usize, usize)>,
+    init_after: Option<(usize, usize)>,
+    ending: Option<Ending>,
+}
+
+impl Move {
+    /// Create a new move from the state of the board before it and the board after it.
+    ///
+    /// Return `None` if the move did not change anything.
+    #[must_use]
+    pub fn new(
+        action: Action,
+        fields: &Grid<Field>,
+        init: Option<(usize, usize)>,
+        board: &Board,
+    ) -> Option<Self> {
+        let changes: Vec<_> = fields
+            .enumerate()
+            .zip(board.fields().iter())
+            .filter(|((_, before), after)| before != after)
+            .map(|((coordinate, &before), &after)| Change {
+                coordinate,
+                before,
+                after,
+            })
+            .collect();
+
+        if changes.is_empty() && init == board.init() {
+            return None;
+        }
+
+        Some(Self {
+            action,
+            changes,
+            init_before: init,
+            init_after: board.init(),
+            ending: None,
+        })
+    }
+
+    /// Set the outcome the move resulted in, if any.
+    #[must_use]
+    pub fn with_outcome(mut self, outcome: Option<Outcome>) -> Self {
+        self.ending = outcome.map(Into::into);
+        self
+    }
+
+    /// Revert the move on the given board.
+    pub fn undo(&self, board: &mut Board) {
+        board.restore(
+            self.changes
+                .iter()
+                .map(|change| (change.coordinate, change.before)),
+            self.init_before,
+        );
+    }
+
+    /// Re-apply the move on the given board and return the kind of outcome it resulted in, if any.
+    #[must_use]
+    pub fn redo(&self, board: &mut Board) -> Option<Ending> {
+        board.restore(
+            self.changes
+                .iter()
+                .map(|change| (change.coordinate, change.after)),
+            self.init_after,
+        );
+        self.ending
+    }
+}
+
+/// A change of a single field.
+#[derive(Clone, Copy, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+struct Change {
+    #[cfg_attr(feature = "serde", serde(with = "crate::game::coordinate"))]
+    coordinate: Coordinate,
+    before: Field,
+    after: Field,
+}
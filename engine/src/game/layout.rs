@@ -0,0 +1,174 @@
+use std::fmt::{Display, Formatter};
+use std::num::NonZero;
+use std::str::FromStr;
+
+use grid2d::{Coordinate, Grid};
+
+pub use self::error::Error;
+pub use self::tile::Tile;
+
+mod bbbv;
+mod error;
+mod mbf;
+mod tile;
+
+/// An explicit placement of mines and duds on a game board.
+///
+/// Games created from a layout do not place mines randomly after the first move.
+///
+/// A layout can be parsed from text, where each line represents a row of the board.
+/// A `*` denotes a mine, a `~` denotes a dud and a `.` denotes a field without a mine,
+/// e.g. `"*..\n.~.\n..."` describes a 3x3 board with a mine and a dud.
+/// Tools which do not know about duds may read them as unknown symbols.
+///
+/// Layouts can also be exchanged in the binary `.mbf` format via
+/// [`Layout::from_mbf()`] and [`Layout::to_mbf()`].
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(into = "String", try_from = "String")
+)]
+pub struct Layout {
+    tiles: Grid<Tile>,
+}
+
+impl Layout {
+    /// Create a layout from lists of coordinates of mines and duds.
+    ///
+    /// Duds are mines as well, so they need not be listed among the mines.
+    ///
+    /// # Errors
+    ///
+    /// Return an [`Error`] if any coordinate lies outside the board.
+    pub fn from_coordinates(
+        width: NonZero<usize>,
+        height: NonZero<usize>,
+        mines: impl IntoIterator<Item = Coordinate>,
+        duds: impl IntoIterator<Item = Coordinate>,
+    ) -> Result<Self, Error> {
+        let mut tiles = Grid::new_default(width, height);
+
+        for (coordinate, tile) in mines
+            .into_iter()
+            .map(|coordinate| (coordinate, Tile::Mine))
+            .chain(duds.into_iter().map(|coordinate| (coordinate, Tile::Dud)))
+        {
+            *tiles
+                .get_mut(coordinate)
+                .ok_or(Error::OutOfBounds(coordinate))? = tile;
+        }
+
+        Ok(Self { tiles })
+    }
+
+    /// Return the width of the board.
+    #[must_use]
+    pub const fn width(&self) -> NonZero<usize> {
+        self.tiles.width()
+    }
+
+    /// Return the height of the board.
+    #[must_use]
+    pub fn height(&self) -> NonZero<usize> {
+        self.tiles.height()
+    }
+
+    /// Return the amount of mines, including duds.
+    #[must_use]
+    pub fn mines(&self) -> usize {
+        self.tiles.iter().filter(|tile| tile.has_mine()).count()
+    }
+
+    /// Return the amount of duds.
+    #[must_use]
+    pub fn duds(&self) -> usize {
+        self.tiles.iter().filter(|&&tile| tile == Tile::Dud).count()
+    }
+
+    /// Return the tiles of the layout.
+    #[must_use]
+    pub const fn tiles(&self) -> &Grid<Tile> {
+        &self.tiles
+    }
+}
+
+impl From<Grid<Tile>> for Layout {
+    fn from(tiles: Grid<Tile>) -> Self {
+        Self { tiles }
+    }
+}
+
+impl FromStr for Layout {
+    type Err = Error;
+
+    /// Parse a layout from text, ignoring blank lines and surrounding whitespace.
+    fn from_str(string: &str) -> Result<Self, Self::Err> {
+        let rows: Vec<&str> = string
+            .lines()
+            .map(str::trim)
+            .filter(|line| !line.is_empty())
+            .collect();
+        let width = rows.first().map_or(0, |row| row.chars().count());
+        let (Some(width), Some(height)) = (NonZero::new(width), NonZero::new(rows.len())) else {
+            return Err(Error::Empty);
+        };
+        let mut parsed = Vec::with_capacity(width.get() * height.get());
+
+        for (y, row) in rows.into_iter().enumerate() {
+            let actual = row.chars().count();
+
+            if actual != width.get() {
+                return Err(Error::RaggedRow {
+                    row: y,
+                    expected: width.get(),
+                    actual,
+                });
+            }
+
+            for (x, symbol) in row.chars().enumerate() {
+                parsed.push(
+                    Tile::try_from(symbol).map_err(|symbol| Error::InvalidSymbol {
+                        symbol,
+                        coordinate: Coordinate::new(x, y),
+                    })?,
+                );
+            }
+        }
+
+        let mut tiles = Grid::new_default(width, height);
+        tiles
+            .iter_mut()
+            .zip(parsed)
+            .for_each(|(tile, parsed)| *tile = parsed);
+        Ok(Self { tiles })
+    }
+}
+
+impl TryFrom<String> for Layout {
+    type Error = Error;
+
+    fn try_from(string: String) -> Result<Self, Self::Error> {
+        string.parse()
+    }
+}
+
+impl From<Layout> for String {
+    fn from(layout: Layout) -> Self {
+        layout.to_string()
+    }
+}
+
+impl Display for Layout {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        for row in self.tiles.rows() {
+            for tile in row {
+                tile.fmt(f)?;
+            }
+
+            writeln!(f)?;
+        }
+
+        Ok(())
+    }
+}
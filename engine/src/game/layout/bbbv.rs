@@ -0,0 +1,138 @@
+use std::collections::BTreeSet;
+
+use grid2d::Coordinate;
+
+use super::Layout;
+use crate::game::neighborhood::Neighborhood;
+use crate::game::topology::Topology;
+
+impl Layout {
+    /// Return the Bechtel's Board Benchmark Value (3BV) of the layout on a board of the given topology
+    /// and neighborhood.
+    ///
+    /// This is the minimum amount of clicks needed to clear the board,
+    /// i.e. the amount of openings plus the amount of numbered fields not bordering any opening.
+    #[must_use]
+    pub fn bbbv(&self, topology: Topology, neighborhood: Neighborhood) -> usize {
+        let mut cleared = BTreeSet::new();
+        let mut bbbv = 0;
+
+        for (coordinate, _) in self.tiles.enumerate() {
+            if self.adjacent_mines(topology, neighborhood, coordinate) == Some(0)
+                && !cleared.contains(&coordinate.into())
+            {
+                self.clear_opening(topology, neighborhood, coordinate, &mut cleared);
+                bbbv += 1;
+            }
+        }
+
+        bbbv + self
+            .tiles
+            .enumerate()
+            .filter(|(coordinate, tile)| {
+                !tile.has_mine() && !cleared.contains(&(*coordinate).into())
+            })
+            .count()
+    }
+
+    /// Mark the opening at the given coordinate and its bordering fields as cleared.
+    fn clear_opening(
+        &self,
+        topology: Topology,
+        neighborhood: Neighborhood,
+        start: Coordinate,
+        cleared: &mut BTreeSet<(usize, usize)>,
+    ) {
+        let mut pending = vec![start];
+        cleared.insert(start.into());
+
+        while let Some(coordinate) = pending.pop() {
+            if self.adjacent_mines(topology, neighborhood, coordinate) != Some(0) {
+                continue;
+            }
+
+            for neighbor in self.neighbors(topology, neighborhood, coordinate) {
+                if cleared.insert(neighbor.into()) {
+                    pending.push(neighbor);
+                }
+            }
+        }
+    }
+
+    /// Return the amount of mines adjacent to the given field, if it does not contain a mine.
+    fn adjacent_mines(
+        &self,
+        topology: Topology,
+        neighborhood: Neighborhood,
+        coordinate: Coordinate,
+    ) -> Option<usize> {
+        self.tiles
+            .get(coordinate)
+            .filter(|tile| !tile.has_mine())
+            .map(|_| {
+                self.neighbors(topology, neighborhood, coordinate)
+                    .into_iter()
+                    .filter_map(|neighbor| self.tiles.get(neighbor))
+                    .filter(|tile| tile.has_mine())
+                    .count()
+            })
+    }
+
+    /// Return the coordinates of the neighbors of the given coordinate.
+    fn neighbors(
+        &self,
+        topology: Topology,
+        neighborhood: Neighborhood,
+        coordinate: Coordinate,
+    ) -> Vec<Coordinate> {
+        topology.neighbors(
+            neighborhood,
+            self.tiles.width(),
+            self.tiles.height(),
+            coordinate,
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::game::layout::Layout;
+    use crate::game::neighborhood::Neighborhood;
+    use crate::game::topology::Topology;
+
+    fn bbbv(layout: &str, topology: Topology) -> usize {
+        layout
+            .parse::<Layout>()
+            .expect("Layout should be valid.")
+            .bbbv(topology, Neighborhood::Moore)
+    }
+
+    #[test]
+    fn empty_board() {
+        assert_eq!(bbbv("...\n...\n...", Topology::Flat), 1);
+    }
+
+    #[test]
+    fn single_opening() {
+        assert_eq!(bbbv("*..\n...\n...", Topology::Flat), 1);
+        assert_eq!(bbbv("*....*", Topology::Flat), 1);
+    }
+
+    #[test]
+    fn isolated_numbers() {
+        assert_eq!(bbbv("...\n.*.\n...", Topology::Flat), 8);
+        assert_eq!(bbbv("*.*", Topology::Flat), 1);
+    }
+
+    #[test]
+    fn openings_and_isolated_numbers() {
+        assert_eq!(bbbv("*.*..", Topology::Flat), 2);
+        assert_eq!(bbbv("..*..\n..*..\n..*..", Topology::Flat), 2);
+    }
+
+    #[test]
+    fn torus() {
+        assert_eq!(bbbv("*..", Topology::Flat), 1);
+        assert_eq!(bbbv("*..", Topology::Torus), 2);
+    }
+}
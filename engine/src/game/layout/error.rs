@@ -0,0 +1,53 @@
+use std::fmt::{Display, Formatter};
+
+use grid2d::Coordinate;
+
+/// Possible errors when constructing a board layout.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum Error {
+    /// The layout does not contain any tiles.
+    Empty,
+    /// A row's width differs from the width of the first row.
+    RaggedRow {
+        /// The index of the offending row.
+        row: usize,
+        /// The width of the first row.
+        expected: usize,
+        /// The width of the offending row.
+        actual: usize,
+    },
+    /// An unknown symbol was encountered.
+    InvalidSymbol {
+        /// The offending symbol.
+        symbol: char,
+        /// The position of the symbol.
+        coordinate: Coordinate,
+    },
+    /// A coordinate lies outside the board.
+    OutOfBounds(Coordinate),
+    /// The data ended prematurely.
+    Truncated,
+    /// The layout exceeds the limits of the format.
+    TooLarge,
+}
+
+impl Display for Error {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Empty => write!(f, "empty layout"),
+            Self::RaggedRow {
+                row,
+                expected,
+                actual,
+            } => write!(f, "row {row} has width {actual} instead of {expected}"),
+            Self::InvalidSymbol { symbol, coordinate } => {
+                write!(f, "invalid symbol {symbol:?} at {coordinate}")
+            }
+            Self::OutOfBounds(coordinate) => write!(f, "coordinate {coordinate} out of bounds"),
+            Self::Truncated => write!(f, "truncated layout data"),
+            Self::TooLarge => write!(f, "layout too large for format"),
+        }
+    }
+}
+
+impl std::error::Error for Error {}
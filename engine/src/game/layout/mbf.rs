@@ -0,0 +1,189 @@
+//! Support for the binary `.mbf` board format.
+//!
+//! An `.mbf` file consists of the board's width and height as one byte each,
+//! followed by the amount of mines as a big-endian 16 bit integer
+//! and the x and y coordinate of each mine as one byte each.
+//!
+//! Since the format knows no duds, the positions of duds are appended in the same manner,
+//! i.e. as a big-endian 16 bit amount of duds followed by their coordinates.
+//! Tools which only read the amount of mines declared in the header ignore this extension.
+
+use std::num::NonZero;
+
+use grid2d::{Coordinate, Grid};
+
+use super::{Error, Layout, Tile};
+
+impl Layout {
+    /// Parse a layout from the binary `.mbf` board format.
+    ///
+    /// Duds are read from the optional extension following the mines.
+    ///
+    /// # Errors
+    ///
+    /// Return an [`Error`] if the data is truncated, the board is empty
+    /// or a coordinate lies outside the board.
+    pub fn from_mbf(bytes: &[u8]) -> Result<Self, Error> {
+        let [width, height, rest @ ..] = bytes else {
+            return Err(Error::Truncated);
+        };
+        let (Some(width), Some(height)) = (
+            NonZero::new(usize::from(*width)),
+            NonZero::new(usize::from(*height)),
+        ) else {
+            return Err(Error::Empty);
+        };
+        let (mines, rest) = read_coordinates(rest)?;
+        let (duds, _) = if rest.is_empty() {
+            (Vec::new(), rest)
+        } else {
+            read_coordinates(rest)?
+        };
+        Self::from_coordinates(width, height, mines, duds)
+    }
+
+    /// Return the layout in the binary `.mbf` board format.
+    ///
+    /// Duds are written as live mines and additionally to the extension following the mines.
+    /// The extension is omitted, if there are no duds.
+    ///
+    /// # Errors
+    ///
+    /// Return [`Error::TooLarge`] if the board's dimensions exceed 255 fields
+    /// or the amount of mines exceeds 65535.
+    pub fn to_mbf(&self) -> Result<Vec<u8>, Error> {
+        let (Ok(width), Ok(height)) = (
+            u8::try_from(self.width().get()),
+            u8::try_from(self.height().get()),
+        ) else {
+            return Err(Error::TooLarge);
+        };
+        let mut bytes = vec![width, height];
+        write_coordinates(&mut bytes, &self.tiles, Tile::has_mine)?;
+
+        if self.duds() > 0 {
+            write_coordinates(&mut bytes, &self.tiles, |tile| tile == Tile::Dud)?;
+        }
+
+        Ok(bytes)
+    }
+}
+
+/// Read a big-endian 16 bit amount of coordinates followed by the coordinates.
+///
+/// Return the coordinates and the remaining bytes.
+fn read_coordinates(bytes: &[u8]) -> Result<(Vec<Coordinate>, &[u8]), Error> {
+    let [high, low, rest @ ..] = bytes else {
+        return Err(Error::Truncated);
+    };
+    let amount = usize::from(u16::from_be_bytes([*high, *low]));
+    let Some((coordinates, rest)) = rest.split_at_checked(amount * 2) else {
+        return Err(Error::Truncated);
+    };
+    Ok((
+        coordinates
+            .chunks_exact(2)
+            .map(|pair| Coordinate::new(pair[0].into(), pair[1].into()))
+            .collect(),
+        rest,
+    ))
+}
+
+/// Write the amount and coordinates of all tiles matching the predicate.
+fn write_coordinates(
+    bytes: &mut Vec<u8>,
+    tiles: &Grid<Tile>,
+    predicate: impl Fn(Tile) -> bool,
+) -> Result<(), Error> {
+    let coordinates: Vec<Coordinate> = tiles
+        .enumerate()
+        .filter(|&(_, &tile)| predicate(tile))
+        .map(|(coordinate, _)| coordinate)
+        .collect();
+    let amount = u16::try_from(coordinates.len()).map_err(|_| Error::TooLarge)?;
+    bytes.extend(amount.to_be_bytes());
+
+    for coordinate in coordinates {
+        bytes.extend([
+            u8::try_from(coordinate.x()).map_err(|_| Error::TooLarge)?,
+            u8::try_from(coordinate.y()).map_err(|_| Error::TooLarge)?,
+        ]);
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use std::num::NonZero;
+
+    use grid2d::Coordinate;
+
+    use crate::game::layout::{Error, Layout};
+
+    #[test]
+    fn round_trip() {
+        let layout: Layout = "*...\n.~..\n...*".parse().expect("Layout should be valid.");
+        let bytes = layout.to_mbf().expect("Layout should fit the format.");
+        assert_eq!(Layout::from_mbf(&bytes), Ok(layout));
+    }
+
+    #[test]
+    fn export_without_duds() {
+        let layout: Layout = "*..\n..*".parse().expect("Layout should be valid.");
+        assert_eq!(layout.to_mbf(), Ok(vec![3, 2, 0, 2, 0, 0, 2, 1]));
+    }
+
+    #[test]
+    fn export_with_duds() {
+        let layout: Layout = "*..\n..~".parse().expect("Layout should be valid.");
+        assert_eq!(
+            layout.to_mbf(),
+            Ok(vec![3, 2, 0, 2, 0, 0, 2, 1, 0, 1, 2, 1])
+        );
+    }
+
+    #[test]
+    fn import_without_extension() {
+        let layout = Layout::from_mbf(&[2, 2, 0, 1, 1, 0]).expect("Data should be valid.");
+        assert_eq!(layout.to_string(), ".*\n..\n");
+    }
+
+    #[test]
+    fn import_truncated() {
+        assert_eq!(Layout::from_mbf(&[]), Err(Error::Truncated));
+        assert_eq!(Layout::from_mbf(&[2, 2]), Err(Error::Truncated));
+        assert_eq!(Layout::from_mbf(&[2, 2, 0]), Err(Error::Truncated));
+        assert_eq!(Layout::from_mbf(&[2, 2, 0, 2, 0, 0]), Err(Error::Truncated));
+        assert_eq!(
+            Layout::from_mbf(&[2, 2, 0, 1, 0, 0, 0, 1]),
+            Err(Error::Truncated)
+        );
+    }
+
+    #[test]
+    fn import_empty() {
+        assert_eq!(Layout::from_mbf(&[0, 2, 0, 0]), Err(Error::Empty));
+        assert_eq!(Layout::from_mbf(&[2, 0, 0, 0]), Err(Error::Empty));
+    }
+
+    #[test]
+    fn import_out_of_bounds() {
+        assert_eq!(
+            Layout::from_mbf(&[2, 2, 0, 1, 2, 0]),
+            Err(Error::OutOfBounds(Coordinate::new(2, 0)))
+        );
+    }
+
+    #[test]
+    fn export_too_large() {
+        let layout = Layout::from_coordinates(
+            NonZero::new(256).expect("Width should be non-zero."),
+            NonZero::<usize>::MIN,
+            [],
+            [],
+        )
+        .expect("Layout should be valid.");
+        assert_eq!(layout.to_mbf(), Err(Error::TooLarge));
+    }
+}
@@ -0,0 +1,50 @@
+use std::fmt::{Display, Formatter};
+
+/// A tile of a board layout.
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+pub enum Tile {
+    /// The tile is free of mines.
+    #[default]
+    Empty,
+    /// The tile contains a live mine.
+    Mine,
+    /// The tile contains a dud.
+    Dud,
+}
+
+impl Tile {
+    /// Return `true` if the tile contains a mine, which may be a dud.
+    #[must_use]
+    pub const fn has_mine(self) -> bool {
+        matches!(self, Self::Mine | Self::Dud)
+    }
+}
+
+impl TryFrom<char> for Tile {
+    type Error = char;
+
+    fn try_from(symbol: char) -> Result<Self, Self::Error> {
+        match symbol {
+            '.' => Ok(Self::Empty),
+            '*' => Ok(Self::Mine),
+            '~' => Ok(Self::Dud),
+            other => Err(other),
+        }
+    }
+}
+
+impl From<Tile> for char {
+    fn from(tile: Tile) -> Self {
+        match tile {
+            Tile::Empty => '.',
+            Tile::Mine => '*',
+            Tile::Dud => '~',
+        }
+    }
+}
+
+impl Display for Tile {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        Display::fmt(&char::from(*self), f)
+    }
+}
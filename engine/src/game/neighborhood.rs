@@ -0,0 +1,94 @@
+use std::num::NonZero;
+
+use grid2d::Coordinate;
+
+/// Offsets of the eight neighbors of a square field.
+const MOORE: [(isize, isize); 8] = [
+    (-1, -1),
+    (0, -1),
+    (1, -1),
+    (-1, 0),
+    (1, 0),
+    (-1, 1),
+    (0, 1),
+    (1, 1),
+];
+
+/// Offsets of the six neighbors of a hexagonal field in an even row.
+const HEXAGONAL_EVEN: [(isize, isize); 6] = [(-1, -1), (0, -1), (-1, 0), (1, 0), (-1, 1), (0, 1)];
+
+/// Offsets of the six neighbors of a hexagonal field in an odd row.
+const HEXAGONAL_ODD: [(isize, isize); 6] = [(0, -1), (1, -1), (-1, 0), (1, 0), (0, 1), (1, 1)];
+
+/// Offsets of the four orthogonal neighbors of a square field.
+const VON_NEUMANN: [(isize, isize); 4] = [(0, -1), (-1, 0), (1, 0), (0, 1)];
+
+/// Offsets of the eight fields a knight can move to in chess.
+const KNIGHT: [(isize, isize); 8] = [
+    (-1, -2),
+    (1, -2),
+    (-2, -1),
+    (2, -1),
+    (-2, 1),
+    (2, 1),
+    (-1, 2),
+    (1, 2),
+];
+
+/// Neighborhood of the fields on the game board, determining which fields are adjacent.
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Neighborhood {
+    /// The eight fields surrounding a square field.
+    #[default]
+    Moore,
+    /// The six fields surrounding a hexagonal field.
+    ///
+    /// The fields are laid out in rows, where odd rows are shifted to the right by half a field.
+    /// On a [`Torus`](crate::Topology::Torus), the board must have an even height,
+    /// so that the shift of the rows continues across the edges.
+    Hexagonal,
+    /// The four fields orthogonally adjacent to a square field, forming a cross.
+    VonNeumann,
+    /// The eight fields a knight can move to in chess.
+    Knight,
+    /// All square fields within the given distance in both directions.
+    ///
+    /// A radius of one is equivalent to the [`Moore`](Self::Moore) neighborhood.
+    /// Since the amount of adjacent mines is limited to 4095, the radius must not exceed 31
+    /// with a single mine per field.
+    Radius(NonZero<u8>),
+}
+
+impl Neighborhood {
+    /// Return the offsets of the neighbors of the field at the given coordinate.
+    #[must_use]
+    pub fn offsets(self, coordinate: Coordinate) -> Vec<(isize, isize)> {
+        match self {
+            Self::Moore => MOORE.to_vec(),
+            Self::Hexagonal if coordinate.y().is_multiple_of(2) => HEXAGONAL_EVEN.to_vec(),
+            Self::Hexagonal => HEXAGONAL_ODD.to_vec(),
+            Self::VonNeumann => VON_NEUMANN.to_vec(),
+            Self::Knight => KNIGHT.to_vec(),
+            Self::Radius(radius) => {
+                let radius = isize::from(radius.get());
+                (-radius..=radius)
+                    .flat_map(|dy| (-radius..=radius).map(move |dx| (dx, dy)))
+                    .filter(|&offset| offset != (0, 0))
+                    .collect()
+            }
+        }
+    }
+
+    /// Return the largest amount of neighbors a field can have.
+    #[must_use]
+    pub fn max_neighbors(self) -> usize {
+        self.offsets(Coordinate::new(0, 0)).len()
+    }
+
+    /// Return `true` if the fields are hexagonal.
+    #[must_use]
+    pub const fn is_hexagonal(self) -> bool {
+        matches!(self, Self::Hexagonal)
+    }
+}
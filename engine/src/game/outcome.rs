@@ -7,6 +7,10 @@ pub enum Outcome {
     Won(Instant),
     /// The game was lost by the player.
     Lost(Instant),
+    /// The time limit of the game ran out.
+    TimedOut(Instant),
+    /// The player used up all moves of the game's move limit.
+    OutOfMoves(Instant),
 }
 
 impl Outcome {
@@ -14,7 +18,15 @@ impl Outcome {
     #[must_use]
     pub const fn end(self) -> Instant {
         match self {
-            Self::Won(time) | Self::Lost(time) => time,
+            Self::Won(time) | Self::Lost(time) | Self::TimedOut(time) | Self::OutOfMoves(time) => {
+                time
+            }
         }
     }
+
+    /// Return `true` if the game ended because it ran out of time or moves.
+    #[must_use]
+    pub const fn exceeded_limit(self) -> bool {
+        matches!(self, Self::TimedOut(_) | Self::OutOfMoves(_))
+    }
 }
@@ -0,0 +1,188 @@
+//! Export of games in the text-based RAW video format, revision 2.
+//!
+//! See <https://www.minesweeper.info/wiki/RAW> for the format's specification.
+
+use std::collections::HashMap;
+use std::fmt::{Display, Formatter};
+
+use grid2d::Coordinate;
+
+use crate::game::Game;
+use crate::game::clock::{Clock, SystemClock};
+use crate::game::difficulty::Difficulty;
+use crate::game::layout::Layout;
+use crate::{Action, Event, MoveResult, Outcome};
+
+/// Size of a square in pixels.
+const SQUARE_SIZE: usize = 16;
+
+/// Position of a flagged square in the cycle of right clicks.
+const FLAGGED: usize = 1;
+
+/// Position of a square marked with a question mark in the cycle of right clicks.
+const QUESTIONED: usize = 2;
+
+/// A game in the RAW video format.
+///
+/// Visiting a field is exported as a left click and chording as a middle click.
+/// Flags and question marks are exported as the right clicks needed to cycle a square
+/// to the respective marker.
+#[derive(Debug)]
+pub struct RawVf<'game, C: Clock = SystemClock> {
+    game: &'game Game<C>,
+    layout: Layout,
+}
+
+impl<'game, C: Clock> RawVf<'game, C> {
+    /// Create a RAW video of the given game with its layout of mines.
+    #[must_use]
+    pub const fn new(game: &'game Game<C>, layout: Layout) -> Self {
+        Self { game, layout }
+    }
+
+    /// Return the name of the game's difficulty level.
+    fn level(&self) -> &'static str {
+        [
+            (Difficulty::Beginner, "Beginner"),
+            (Difficulty::Intermediate, "Intermediate"),
+            (Difficulty::Expert, "Expert"),
+        ]
+        .into_iter()
+        .find(|(difficulty, _)| {
+            difficulty.width() == self.game.config.width()
+                && difficulty.height() == self.game.config.height()
+                && difficulty.mines() == self.game.config.mines()
+        })
+        .map_or("Custom", |(_, level)| level)
+    }
+
+    /// Return `true` if question marks have been used during the game.
+    fn marks(&self) -> bool {
+        self.game.replay.entries().iter().any(|entry| {
+            matches!(
+                entry.event(),
+                Event::Action {
+                    action: Action::ToggleMark(_),
+                    ..
+                }
+            )
+        })
+    }
+}
+
+impl<C: Clock> Display for RawVf<'_, C> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        writeln!(f, "RawVF_Version: Rev2")?;
+        writeln!(f, "Program: {}", env!("CARGO_PKG_NAME"))?;
+        writeln!(f, "Version: {}", env!("CARGO_PKG_VERSION"))?;
+        writeln!(f, "Level: {}", self.level())?;
+        writeln!(f, "Width: {}", self.layout.width())?;
+        writeln!(f, "Height: {}", self.layout.height())?;
+        writeln!(f, "Mines: {}", self.layout.mines())?;
+        let marks = self.marks();
+        writeln!(f, "Marks: {}", if marks { "On" } else { "Off" })?;
+        writeln!(f, "Mode: Classic")?;
+        writeln!(
+            f,
+            "BBBV: {}",
+            self.layout.bbbv(
+                self.game.config().topology(),
+                self.game.config().neighborhood()
+            )
+        )?;
+
+        if self.game.is_over() {
+            writeln!(f, "Time: {:.3}", self.game.duration().as_secs_f64())?;
+        }
+
+        writeln!(f, "Board:")?;
+
+        for row in self.layout.tiles().rows() {
+            for tile in row {
+                write!(f, "{}", if tile.has_mine() { '*' } else { '0' })?;
+            }
+
+            writeln!(f)?;
+        }
+
+        writeln!(f, "Events:")?;
+        writeln!(f, "0.000 start")?;
+
+        // Right clicks cycle a square through no marker, a flag and, if enabled, a question mark.
+        let cycle = if marks { 3 } else { 2 };
+        let mut markers = HashMap::new();
+
+        for entry in self.game.replay.entries() {
+            let Event::Action { action, result } = *entry.event() else {
+                continue;
+            };
+
+            // Moves outside of the board are not clicks on any square.
+            if result == MoveResult::InvalidPosition {
+                continue;
+            }
+
+            let (press, release, coordinate, clicks) = match action {
+                Action::Visit(coordinate) => {
+                    // Visiting removes question marks, but leaves flagged squares untouched.
+                    if markers.get(&coordinate) == Some(&QUESTIONED) {
+                        markers.remove(&coordinate);
+                    }
+                    ("lc", "lr", coordinate, 1)
+                }
+                Action::ToggleFlag(coordinate)
+                | Action::RaiseFlag(coordinate)
+                | Action::LowerFlag(coordinate)
+                | Action::ToggleMark(coordinate) => {
+                    let marker = markers.get(&coordinate).copied().unwrap_or_default();
+                    let target = match (action, marker) {
+                        (Action::ToggleFlag(_) | Action::LowerFlag(_), FLAGGED)
+                        | (Action::ToggleMark(_), QUESTIONED) => 0,
+                        (Action::ToggleMark(_), _) => QUESTIONED,
+                        (Action::LowerFlag(_), marker) => marker,
+                        _ => FLAGGED,
+                    };
+                    markers.insert(coordinate, target);
+                    ("rc", "rr", coordinate, (target + cycle - marker) % cycle)
+                }
+                Action::Chord(coordinate) => ("mc", "mr", coordinate, 1),
+                Action::VisitAllNonFlaggedFields => continue,
+            };
+            let time = entry.offset().as_secs_f64();
+            let position = Position(coordinate);
+
+            for _ in 0..clicks {
+                writeln!(f, "{time:.3} {press} {position}")?;
+                writeln!(f, "{time:.3} {release} {position}")?;
+            }
+        }
+
+        match self.game.outcome {
+            Some(Outcome::Won(_)) => {
+                writeln!(f, "{:.3} won", self.game.duration().as_secs_f64())
+            }
+            Some(Outcome::Lost(_)) => {
+                writeln!(f, "{:.3} blast", self.game.duration().as_secs_f64())
+            }
+            Some(Outcome::TimedOut(_) | Outcome::OutOfMoves(_)) | None => Ok(()),
+        }
+    }
+}
+
+/// The position of a square in an event, i.e. its one-based column and row
+/// followed by the pixel coordinates of its center.
+struct Position(Coordinate);
+
+impl Display for Position {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let (x, y) = (self.0.x(), self.0.y());
+        write!(
+            f,
+            "{} {} ({} {})",
+            x + 1,
+            y + 1,
+            x * SQUARE_SIZE + SQUARE_SIZE / 2,
+            y * SQUARE_SIZE + SQUARE_SIZE / 2
+        )
+    }
+}
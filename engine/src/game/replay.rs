@@ -0,0 +1,100 @@
+use std::time::Duration;
+
+pub use self::entry::Entry;
+pub use self::event::Event;
+use crate::Error;
+use crate::game::Game;
+use crate::game::clock::{Clock, ManualClock};
+use crate::game::config::Config;
+use crate::game::layout::Layout;
+
+mod entry;
+mod event;
+
+/// A recording of all actions taken during a game.
+///
+/// Along with the game's configuration, including its seed, the recording suffices to reproduce
+/// the game, e.g. to review lost games or to verify the times of won games.
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Replay {
+    config: Config,
+    layout: Option<Layout>,
+    entries: Vec<Entry>,
+}
+
+impl Replay {
+    /// Create a new, empty recording of a game with the given configuration.
+    #[must_use]
+    pub const fn new(config: Config, layout: Option<Layout>) -> Self {
+        Self {
+            config,
+            layout,
+            entries: Vec::new(),
+        }
+    }
+
+    /// Return the configuration of the recorded game.
+    #[must_use]
+    pub const fn config(&self) -> &Config {
+        &self.config
+    }
+
+    /// Return the layout of the recorded game, if it was not randomly generated.
+    #[must_use]
+    pub const fn layout(&self) -> Option<&Layout> {
+        self.layout.as_ref()
+    }
+
+    /// Return the recorded entries.
+    #[must_use]
+    pub fn entries(&self) -> &[Entry] {
+        &self.entries
+    }
+
+    /// Return the time offset of the last recorded entry.
+    #[must_use]
+    pub fn duration(&self) -> Duration {
+        self.entries.last().map_or(Duration::ZERO, Entry::offset)
+    }
+
+    /// Play the recorded entries on a fresh game and return it.
+    ///
+    /// The game's clock is advanced to the time offset of each entry before playing it,
+    /// so that the times of the returned game match the recorded ones.
+    ///
+    /// # Errors
+    ///
+    /// Return an [`Error`] if the game cannot be created or any entry results in a different
+    /// result than recorded.
+    pub fn play(&self) -> Result<Game<ManualClock>, Error> {
+        let mut game = Game::with_clock(self.config, ManualClock::default())?;
+
+        if let Some(layout) = &self.layout {
+            game.board.place(layout);
+            game.replay.layout = Some(layout.clone());
+        }
+
+        for (step, entry) in self.entries.iter().enumerate() {
+            let elapsed = game.clock.now().duration_since(game.start);
+            game.clock.advance(entry.offset().saturating_sub(elapsed));
+            let reproduced = match *entry.event() {
+                Event::Action { action, result } => game.make_move(action) == Some(result),
+                Event::Undo => game.undo(),
+                Event::Redo => game.redo(),
+                Event::Expire => game.expire(),
+            };
+
+            if !reproduced {
+                return Err(Error::ReplayDiverged { step });
+            }
+        }
+
+        Ok(game)
+    }
+
+    /// Record an event with the given time offset.
+    pub fn record(&mut self, offset: Duration, event: Event) {
+        self.entries.push(Entry::new(offset, event));
+    }
+}
@@ -0,0 +1,31 @@
+use std::time::Duration;
+
+use crate::game::replay::Event;
+
+/// A recorded event with its time offset from the start of the game.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Entry {
+    offset: Duration,
+    event: Event,
+}
+
+impl Entry {
+    /// Create a new entry.
+    #[must_use]
+    pub const fn new(offset: Duration, event: Event) -> Self {
+        Self { offset, event }
+    }
+
+    /// Return the time offset from the start of the game.
+    #[must_use]
+    pub const fn offset(&self) -> Duration {
+        self.offset
+    }
+
+    /// Return the recorded event.
+    #[must_use]
+    pub const fn event(&self) -> &Event {
+        &self.event
+    }
+}
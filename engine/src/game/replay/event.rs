@@ -0,0 +1,20 @@
+use crate::{Action, MoveResult};
+
+/// An event during a game.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Event {
+    /// The player took an action.
+    Action {
+        /// The action taken.
+        action: Action,
+        /// The result of the action.
+        result: MoveResult,
+    },
+    /// The player undid the last move.
+    Undo,
+    /// The player redid the last undone move.
+    Redo,
+    /// The game ran out of time.
+    Expire,
+}
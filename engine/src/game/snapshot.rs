@@ -0,0 +1,157 @@
+use std::fmt::{Display, Formatter};
+use std::time::{Duration, SystemTime};
+
+use serde::{Deserialize, Serialize};
+
+use crate::game::Game;
+use crate::game::board::Board;
+use crate::game::clock::Clock;
+use crate::game::config::Config;
+use crate::game::ending::Ending;
+use crate::game::history::History;
+use crate::game::replay::Replay;
+
+/// Serializable representation of a [`Game`].
+///
+/// Since [`Instant`](std::time::Instant)s cannot be persisted, the elapsed time is stored instead.
+/// When restoring a game, its start is set back by the elapsed time, so that the clock continues
+/// where it stopped.
+/// A paused game remains paused after restoring.
+/// The history of moves is stored as well, so that moves can still be undone and redone.
+#[derive(Debug, Deserialize, Serialize)]
+pub struct Snapshot {
+    board: Board,
+    config: Config,
+    started: SystemTime,
+    elapsed: Duration,
+    paused: bool,
+    outcome: Option<Ending>,
+    moves: usize,
+    history: History,
+    undos: usize,
+    replay: Replay,
+}
+
+/// The elapsed time of a snapshot cannot be represented on the system's monotonic clock.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct ElapsedTimeOutOfRange;
+
+impl Display for ElapsedTimeOutOfRange {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "elapsed time out of range")
+    }
+}
+
+impl<C: Clock> From<Game<C>> for Snapshot {
+    fn from(game: Game<C>) -> Self {
+        Self {
+            started: game.started,
+            elapsed: game.duration(),
+            paused: game.is_paused(),
+            outcome: game.outcome.map(Into::into),
+            moves: game.moves,
+            board: game.board,
+            config: game.config,
+            history: game.history,
+            undos: game.undos,
+            replay: game.replay,
+        }
+    }
+}
+
+impl<C: Clock + Default> TryFrom<Snapshot> for Game<C> {
+    type Error = ElapsedTimeOutOfRange;
+
+    fn try_from(snapshot: Snapshot) -> Result<Self, Self::Error> {
+        let clock = C::default();
+        let now = clock.now();
+        let start = now
+            .checked_sub(snapshot.elapsed)
+            .ok_or(ElapsedTimeOutOfRange)?;
+        Ok(Self {
+            board: snapshot.board,
+            config: snapshot.config,
+            clock,
+            start,
+            started: snapshot.started,
+            paused: snapshot.paused.then_some(now),
+            pauses: Duration::ZERO,
+            outcome: snapshot.outcome.map(|ending| ending.at(now)),
+            moves: snapshot.moves,
+            history: snapshot.history,
+            undos: snapshot.undos,
+            replay: snapshot.replay,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use grid2d::Coordinate;
+
+    use crate::{Action, Game, Layout, ManualClock, Outcome, State};
+
+    fn game(layout: &str) -> Game<ManualClock> {
+        let layout: Layout = layout.parse().expect("Layout should be valid.");
+        Game::with_layout(&layout, ManualClock::default()).expect("Layout should be playable.")
+    }
+
+    fn board(game: &Game<ManualClock>) -> Vec<String> {
+        game.rows()
+            .map(|row| row.map(|view| view.to_string()).collect())
+            .collect()
+    }
+
+    fn round_trip(game: &Game<ManualClock>) -> Game<ManualClock> {
+        let json = serde_json::to_string(game).expect("Game should serialize.");
+        serde_json::from_str(&json).expect("Game should deserialize.")
+    }
+
+    #[test]
+    fn mid_game() {
+        let mut game = game("*..\n.*.\n...");
+        game.next_round(Action::Visit(Coordinate::new(2, 2)));
+        game.next_round(Action::ToggleFlag(Coordinate::new(0, 0)));
+        game.clock_mut().advance(Duration::from_secs(5));
+        let mut restored = round_trip(&game);
+        assert_eq!(board(&restored), board(&game));
+        assert_eq!(restored.duration(), Duration::from_secs(5));
+        assert_eq!(restored.outcome(), None);
+        assert!(restored.undo());
+        assert_eq!(board(&restored), ["■■■", "■■■", "■■1"]);
+        assert!(restored.redo());
+        assert_eq!(board(&restored), board(&game));
+    }
+
+    #[test]
+    fn paused_game() {
+        let mut game = game("*..\n.*.\n...");
+        game.next_round(Action::Visit(Coordinate::new(2, 2)));
+        game.clock_mut().advance(Duration::from_secs(3));
+        assert!(game.pause());
+        game.clock_mut().advance(Duration::from_secs(10));
+        let mut restored = round_trip(&game);
+        assert!(restored.is_paused());
+        assert_eq!(restored.duration(), Duration::from_secs(3));
+        assert!(matches!(
+            restored.next_round(Action::Visit(Coordinate::new(0, 2))),
+            Some(State::Paused)
+        ));
+        assert!(restored.resume());
+        restored.clock_mut().advance(Duration::from_secs(2));
+        assert_eq!(restored.duration(), Duration::from_secs(5));
+    }
+
+    #[test]
+    fn lost_game() {
+        let mut game = game("*..\n.*.\n...");
+        game.next_round(Action::Visit(Coordinate::new(0, 0)));
+        let mut restored = round_trip(&game);
+        assert!(matches!(restored.outcome(), Some(Outcome::Lost(_))));
+        assert_eq!(board(&restored), board(&game));
+        assert!(restored.undo());
+        assert!(!restored.is_over());
+    }
+}
@@ -1,4 +1,6 @@
-use crate::Outcome;
+use grid2d::Coordinate;
+
+use crate::{Error, Outcome};
 
 /// State of the game after a player move.
 pub enum State {
@@ -6,6 +8,15 @@ pub enum State {
     InvalidMove,
     /// The game may continue.
     Continue,
+    /// The player stepped onto a dud at the given coordinate, but the game may continue.
+    DudTriggered(Coordinate),
+    /// The player stepped onto a mine at the given coordinate, which cost a life,
+    /// but the game may continue.
+    LifeLost(Coordinate),
     /// The game is over.
     GameOver(Outcome),
+    /// The game is paused, so the move was rejected.
+    Paused,
+    /// The move could not be made due to an error.
+    Error(Error),
 }
@@ -0,0 +1,73 @@
+use std::fmt::{Display, Formatter};
+use std::time::Duration;
+
+/// Statistics of a finished game, which players commonly compare.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct Statistics {
+    bbbv: usize,
+    clicks: usize,
+    duration: Duration,
+}
+
+impl Statistics {
+    /// Create new statistics.
+    #[must_use]
+    pub const fn new(bbbv: usize, clicks: usize, duration: Duration) -> Self {
+        Self {
+            bbbv,
+            clicks,
+            duration,
+        }
+    }
+
+    /// Return the Bechtel's Board Benchmark Value (3BV) of the board.
+    #[must_use]
+    pub const fn bbbv(&self) -> usize {
+        self.bbbv
+    }
+
+    /// Return the amount of clicks, i.e. visits, flags and chords, used during the game.
+    #[must_use]
+    pub const fn clicks(&self) -> usize {
+        self.clicks
+    }
+
+    /// Return the duration of the game.
+    #[must_use]
+    pub const fn duration(&self) -> Duration {
+        self.duration
+    }
+
+    /// Return the 3BV cleared per second.
+    #[must_use]
+    #[expect(clippy::cast_precision_loss)]
+    pub fn bbbv_per_second(&self) -> f64 {
+        let seconds = self.duration.as_secs_f64();
+
+        if seconds == 0.0 {
+            return 0.0;
+        }
+
+        self.bbbv as f64 / seconds
+    }
+
+    /// Return the efficiency, i.e. the ratio of the 3BV to the clicks used.
+    #[must_use]
+    #[expect(clippy::cast_precision_loss)]
+    pub fn efficiency(&self) -> f64 {
+        if self.clicks == 0 {
+            return 0.0;
+        }
+
+        self.bbbv as f64 / self.clicks as f64
+    }
+}
+
+impl Display for Statistics {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        writeln!(f, "3BV: {}", self.bbbv)?;
+        writeln!(f, "Clicks: {}", self.clicks)?;
+        writeln!(f, "3BV/s: {:.2}", self.bbbv_per_second())?;
+        write!(f, "Efficiency: {:.0}%", self.efficiency() * 100.0)
+    }
+}
@@ -0,0 +1,74 @@
+use std::num::NonZero;
+
+use grid2d::Coordinate;
+
+use crate::game::neighborhood::Neighborhood;
+
+/// Topology of the game board, determining which fields are adjacent across its edges.
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Topology {
+    /// The board ends at its edges, so that fields at the edges have fewer neighbors.
+    #[default]
+    Flat,
+    /// The board wraps around at its edges, so that every field has all of its neighbors,
+    /// if the board is large enough.
+    Torus,
+}
+
+impl Topology {
+    /// Return the coordinates of the neighbors of the given coordinate
+    /// within the given neighborhood on a board of the given size.
+    ///
+    /// Each neighbor is only returned once and a field is never its own neighbor,
+    /// even if the board is too small for all neighbors to be distinct.
+    #[must_use]
+    pub fn neighbors(
+        self,
+        neighborhood: Neighborhood,
+        width: NonZero<usize>,
+        height: NonZero<usize>,
+        coordinate: Coordinate,
+    ) -> Vec<Coordinate> {
+        let offsets = neighborhood.offsets(coordinate);
+        let mut neighbors = Vec::with_capacity(offsets.len());
+
+        for (dx, dy) in offsets {
+            let Some(x) = self.shift(coordinate.x(), dx, width) else {
+                continue;
+            };
+
+            let Some(y) = self.shift(coordinate.y(), dy, height) else {
+                continue;
+            };
+
+            let neighbor = Coordinate::new(x, y);
+
+            if neighbor != coordinate && !neighbors.contains(&neighbor) {
+                neighbors.push(neighbor);
+            }
+        }
+
+        neighbors
+    }
+
+    /// Shift the given position by the offset along an axis of the given size.
+    ///
+    /// Return `None` if the position leaves the board.
+    fn shift(self, position: usize, offset: isize, size: NonZero<usize>) -> Option<usize> {
+        match self {
+            Self::Flat => position
+                .checked_add_signed(offset)
+                .filter(|&position| position < size.get()),
+            Self::Torus => {
+                let distance = offset.unsigned_abs() % size;
+
+                if offset.is_negative() {
+                    Some((position + size.get() - distance) % size)
+                } else {
+                    Some((position + distance) % size)
+                }
+            }
+        }
+    }
+}
@@ -4,9 +4,22 @@ pub use grid2d as grid;
 
 pub use self::game::Game;
 pub use self::game::action::Action;
+pub use self::game::board::MoveResult;
 pub use self::game::board::error::Error;
 pub use self::game::board::field::View;
+pub use self::game::change::Change;
+pub use self::game::clock::{Clock, ManualClock, SystemClock};
+pub use self::game::config::Config;
+pub use self::game::difficulty::Difficulty;
+pub use self::game::layout::{Error as LayoutError, Layout, Tile};
+pub use self::game::neighborhood::Neighborhood;
 pub use self::game::outcome::Outcome;
+pub use self::game::rawvf::RawVf;
+pub use self::game::replay::{Entry, Event, Replay};
 pub use self::game::state::State;
+pub use self::game::statistics::Statistics;
+pub use self::game::topology::Topology;
+pub use self::solver::{Hint, Reason, Solver};
 
 mod game;
+mod solver;
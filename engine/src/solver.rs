@@ -0,0 +1,225 @@
+use std::collections::BTreeMap;
+
+use grid2d::{Coordinate, Grid};
+
+pub use self::cell::Cell;
+use self::constraint::Constraint;
+pub use self::hint::{Hint, Reason};
+use crate::{Neighborhood, Topology, View};
+
+mod cell;
+mod constraint;
+mod hint;
+mod probabilities;
+#[cfg(test)]
+mod testing;
+
+/// A logical solver, which finds fields that are provably safe or provably mined.
+///
+/// The solver only uses information that is visible to the player.
+/// Flags are not trusted, since the player may have misplaced them.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Solver {
+    cells: Grid<Cell>,
+    topology: Topology,
+    neighborhood: Neighborhood,
+}
+
+impl Solver {
+    /// Create a new solver from the views of a game board's fields.
+    #[must_use]
+    pub fn new(views: &Grid<View>) -> Self {
+        let mut cells = Grid::new_default(views.width(), views.height());
+        cells
+            .iter_mut()
+            .zip(views.iter())
+            .for_each(|(cell, &view)| *cell = view.into());
+        cells.into()
+    }
+
+    /// Set the topology of the game board, which determines the neighbors of its fields.
+    #[must_use]
+    pub const fn with_topology(mut self, topology: Topology) -> Self {
+        self.topology = topology;
+        self
+    }
+
+    /// Set the neighborhood of the fields, which determines the neighbors of each field.
+    #[must_use]
+    pub const fn with_neighborhood(mut self, neighborhood: Neighborhood) -> Self {
+        self.neighborhood = neighborhood;
+        self
+    }
+
+    /// Return a hint, if any field can be deduced.
+    ///
+    /// Safe fields are preferred over mined ones.
+    #[must_use]
+    pub fn hint(&self) -> Option<Hint> {
+        let hints = self.hints();
+        hints
+            .iter()
+            .find(|hint| hint.is_safe())
+            .or_else(|| hints.first())
+            .copied()
+    }
+
+    /// Return hints for all fields, which can currently be deduced.
+    ///
+    /// Flagged fields are only included, if they are provably safe, i.e. the flag is misplaced.
+    #[must_use]
+    pub fn hints(&self) -> Vec<Hint> {
+        let constraints = self.constraints();
+        let mut hints = BTreeMap::new();
+
+        for constraint in &constraints {
+            if constraint.mines() == 0 {
+                for &field in constraint.fields() {
+                    hints.entry(field).or_insert_with(|| {
+                        Hint::new(field.into(), true, Reason::Satisfied(constraint.origin()))
+                    });
+                }
+            } else if constraint.mines() == constraint.fields().len() {
+                for &field in constraint.fields() {
+                    hints.entry(field).or_insert_with(|| {
+                        Hint::new(field.into(), false, Reason::Saturated(constraint.origin()))
+                    });
+                }
+            }
+        }
+
+        for hint in Self::subset_hints(&constraints) {
+            hints.entry(hint.coordinate().into()).or_insert(hint);
+        }
+
+        hints
+            .into_values()
+            .filter(|hint| hint.is_safe() || !self.is_flagged(hint.coordinate()))
+            .collect()
+    }
+
+    /// Return all constraints of cleared fields with covered neighbors.
+    fn constraints(&self) -> Vec<Constraint> {
+        self.cells
+            .enumerate()
+            .filter_map(|(coordinate, _)| {
+                Constraint::new(&self.cells, self.topology, self.neighborhood, coordinate)
+            })
+            .collect()
+    }
+
+    /// Return hints from comparing constraints, whose fields are subsets of one another.
+    fn subset_hints(constraints: &[Constraint]) -> Vec<Hint> {
+        let mut index: BTreeMap<(usize, usize), Vec<&Constraint>> = BTreeMap::new();
+
+        for constraint in constraints {
+            for &field in constraint.fields() {
+                index.entry(field).or_default().push(constraint);
+            }
+        }
+
+        let mut hints = Vec::new();
+
+        for subset in constraints {
+            let Some(candidates) = subset.fields().first().and_then(|field| index.get(field))
+            else {
+                continue;
+            };
+
+            for superset in candidates {
+                if superset.fields().len() <= subset.fields().len()
+                    || !subset.fields().is_subset(superset.fields())
+                {
+                    continue;
+                }
+
+                let Some(mines) = superset.mines().checked_sub(subset.mines()) else {
+                    continue;
+                };
+
+                let remaining = superset.fields().len() - subset.fields().len();
+                let safe = match mines {
+                    0 => true,
+                    mines if mines == remaining => false,
+                    _ => continue,
+                };
+                let reason = Reason::Subset {
+                    subset: subset.origin(),
+                    superset: superset.origin(),
+                };
+                hints.extend(
+                    superset
+                        .fields()
+                        .difference(subset.fields())
+                        .map(|&field| Hint::new(field.into(), safe, reason)),
+                );
+            }
+        }
+
+        hints
+    }
+
+    /// Return `true` if the field at the given coordinate has been flagged.
+    fn is_flagged(&self, coordinate: Coordinate) -> bool {
+        matches!(
+            self.cells.get(coordinate),
+            Some(Cell::Covered { flagged: true })
+        )
+    }
+}
+
+impl From<Grid<Cell>> for Solver {
+    fn from(cells: Grid<Cell>) -> Self {
+        Self {
+            cells,
+            topology: Topology::default(),
+            neighborhood: Neighborhood::default(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Solver;
+    use super::testing::cases;
+
+    #[test]
+    fn hints_are_sound() {
+        let mut hints = 0;
+
+        for case in cases(200) {
+            let solutions = case.solutions();
+
+            for hint in Solver::new(&case.views).hints() {
+                let coordinate = hint.coordinate();
+                assert_eq!(
+                    hint.is_mine(),
+                    case.mines.contains(&coordinate),
+                    "{hint:?} of\n{:?}",
+                    case.views
+                );
+                assert!(
+                    solutions
+                        .iter()
+                        .all(|mines| mines.contains(&coordinate) == hint.is_mine()),
+                    "{hint:?} of\n{:?}",
+                    case.views
+                );
+                hints += 1;
+            }
+        }
+
+        assert!(hints > 0);
+    }
+
+    #[test]
+    fn hint_prefers_safe_fields() {
+        for case in cases(200) {
+            let solver = Solver::new(&case.views);
+
+            if solver.hints().iter().any(super::Hint::is_safe) {
+                assert!(solver.hint().is_some_and(|hint| hint.is_safe()));
+            }
+        }
+    }
+}
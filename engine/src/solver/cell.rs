@@ -0,0 +1,38 @@
+use crate::View;
+
+/// Knowledge of the solver about a single field.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum Cell {
+    /// The field is covered and may or may not contain a mine.
+    Covered {
+        /// Whether the player put a flag onto the field.
+        flagged: bool,
+    },
+    /// The field is known to contain a live mine.
+    Mine,
+    /// The field is known to contain a dud.
+    Dud,
+    /// The field is clear.
+    Clear {
+        /// The amount of mines adjacent to the field.
+        adjacent_mines: u16,
+    },
+}
+
+impl Default for Cell {
+    fn default() -> Self {
+        Self::Covered { flagged: false }
+    }
+}
+
+impl From<View> for Cell {
+    fn from(view: View) -> Self {
+        match view {
+            View::Covered | View::Questioned => Self::Covered { flagged: false },
+            View::Flag | View::Flags(_) => Self::Covered { flagged: true },
+            View::SteppedOnDud => Self::Dud,
+            View::SteppedOnMine | View::Mine | View::Mines(_) => Self::Mine,
+            View::Clear { adjacent_mines } => Self::Clear { adjacent_mines },
+        }
+    }
+}
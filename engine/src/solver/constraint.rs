@@ -0,0 +1,81 @@
+use std::collections::BTreeSet;
+
+use grid2d::{Coordinate, Grid};
+
+use crate::solver::cell::Cell;
+use crate::{Neighborhood, Topology};
+
+/// A constraint on the covered neighbors of a cleared field.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Constraint {
+    origin: Coordinate,
+    fields: BTreeSet<(usize, usize)>,
+    mines: usize,
+}
+
+impl Constraint {
+    /// Create a constraint for the cleared field at the given coordinate
+    /// on a board of the given topology and neighborhood.
+    ///
+    /// Return `None` if the field is not cleared, has no covered neighbors
+    /// or contradicts the mines known around it.
+    #[must_use]
+    pub fn new(
+        cells: &Grid<Cell>,
+        topology: Topology,
+        neighborhood: Neighborhood,
+        origin: Coordinate,
+    ) -> Option<Self> {
+        let Cell::Clear { adjacent_mines } = cells.get(origin)? else {
+            return None;
+        };
+
+        let mut fields = BTreeSet::new();
+        let mut known_mines: usize = 0;
+
+        for coordinate in topology.neighbors(neighborhood, cells.width(), cells.height(), origin) {
+            let Some(cell) = cells.get(coordinate) else {
+                continue;
+            };
+
+            match cell {
+                Cell::Covered { .. } => {
+                    fields.insert(coordinate.into());
+                }
+                Cell::Mine | Cell::Dud => known_mines += 1,
+                Cell::Clear { .. } => {}
+            }
+        }
+
+        if fields.is_empty() {
+            return None;
+        }
+
+        usize::from(*adjacent_mines)
+            .checked_sub(known_mines)
+            .filter(|&mines| mines <= fields.len())
+            .map(|mines| Self {
+                origin,
+                fields,
+                mines,
+            })
+    }
+
+    /// Return the coordinate of the cleared field.
+    #[must_use]
+    pub const fn origin(&self) -> Coordinate {
+        self.origin
+    }
+
+    /// Return the covered fields around the cleared field.
+    #[must_use]
+    pub const fn fields(&self) -> &BTreeSet<(usize, usize)> {
+        &self.fields
+    }
+
+    /// Return the amount of mines among the covered fields.
+    #[must_use]
+    pub const fn mines(&self) -> usize {
+        self.mines
+    }
+}
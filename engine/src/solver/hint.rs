@@ -0,0 +1,49 @@
+use grid2d::Coordinate;
+
+pub use self::reason::Reason;
+
+mod reason;
+
+/// A field that is provably safe or provably mined.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct Hint {
+    coordinate: Coordinate,
+    safe: bool,
+    reason: Reason,
+}
+
+impl Hint {
+    /// Create a new hint.
+    #[must_use]
+    pub const fn new(coordinate: Coordinate, safe: bool, reason: Reason) -> Self {
+        Self {
+            coordinate,
+            safe,
+            reason,
+        }
+    }
+
+    /// Return the coordinate of the field.
+    #[must_use]
+    pub const fn coordinate(&self) -> Coordinate {
+        self.coordinate
+    }
+
+    /// Return `true` if the field is safe to visit.
+    #[must_use]
+    pub const fn is_safe(&self) -> bool {
+        self.safe
+    }
+
+    /// Return `true` if the field contains a mine.
+    #[must_use]
+    pub const fn is_mine(&self) -> bool {
+        !self.safe
+    }
+
+    /// Return the reason why the field is safe or contains a mine.
+    #[must_use]
+    pub const fn reason(&self) -> Reason {
+        self.reason
+    }
+}
@@ -0,0 +1,40 @@
+use std::fmt::{Display, Formatter};
+
+use grid2d::Coordinate;
+
+/// Reason for a [`Hint`](super::Hint).
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum Reason {
+    /// All mines around the cleared field have been found, so its other neighbors are safe.
+    Satisfied(Coordinate),
+    /// The cleared field has as many covered neighbors as it has mines left,
+    /// so all of them contain a mine.
+    Saturated(Coordinate),
+    /// The covered neighbors of one cleared field are a subset of the covered neighbors
+    /// of another one, which determines the fields that are not shared.
+    Subset {
+        /// The cleared field, whose covered neighbors are a subset.
+        subset: Coordinate,
+        /// The cleared field, whose covered neighbors are a superset.
+        superset: Coordinate,
+    },
+}
+
+impl Display for Reason {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Satisfied(coordinate) => {
+                write!(f, "all mines around {coordinate} have been found")
+            }
+            Self::Saturated(coordinate) => {
+                write!(f, "all covered fields around {coordinate} are mines")
+            }
+            Self::Subset { subset, superset } => {
+                write!(
+                    f,
+                    "the fields around {superset} include the ones around {subset}"
+                )
+            }
+        }
+    }
+}
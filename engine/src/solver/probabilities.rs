@@ -0,0 +1,513 @@
+use std::collections::{BTreeMap, BTreeSet};
+
+use grid2d::Grid;
+
+use crate::solver::Solver;
+use crate::solver::cell::Cell;
+use crate::solver::constraint::Constraint;
+
+/// Maximum amount of steps to enumerate the solutions of a single group of constraints.
+const MAX_STEPS: usize = 1 << 20;
+
+impl Solver {
+    /// Return the probability of each field to contain a live mine.
+    ///
+    /// The `mines` and `duds` are the total amounts of mines and duds on the game board.
+    ///
+    /// The probabilities are exact, as long as the solutions of each group of constraints,
+    /// i.e. cleared fields sharing covered neighbors, can be enumerated within a fixed budget.
+    /// Fields of groups exceeding this budget are approximated by the highest mine density among
+    /// the cleared fields around them. The amount of mines expected on these fields is then
+    /// deducted from the mines, which are distributed among the remaining covered fields.
+    #[must_use]
+    #[expect(clippy::cast_precision_loss)]
+    pub fn probabilities(&self, mines: usize, duds: usize) -> Grid<f64> {
+        let known_mines = self
+            .cells
+            .iter()
+            .filter(|cell| matches!(cell, Cell::Mine | Cell::Dud))
+            .count();
+        let known_duds = self
+            .cells
+            .iter()
+            .filter(|cell| matches!(cell, Cell::Dud))
+            .count();
+        let mines = mines.saturating_sub(known_mines);
+        let live_mines = mines.saturating_sub(duds.saturating_sub(known_duds));
+        let live_ratio = if mines == 0 {
+            0.0
+        } else {
+            live_mines as f64 / mines as f64
+        };
+        let mine_probabilities = self.mine_probabilities(mines);
+        let mut probabilities = Grid::new_default(self.cells.width(), self.cells.height());
+        probabilities
+            .iter_mut()
+            .zip(self.cells.enumerate())
+            .for_each(|(probability, (coordinate, cell))| {
+                *probability = match cell {
+                    Cell::Covered { .. } => {
+                        mine_probabilities
+                            .get(&coordinate.into())
+                            .copied()
+                            .unwrap_or_default()
+                            * live_ratio
+                    }
+                    Cell::Mine => 1.0,
+                    Cell::Dud | Cell::Clear { .. } => 0.0,
+                };
+            });
+        probabilities
+    }
+
+    /// Return the probability of each covered field to contain a mine,
+    /// given the amount of mines among all covered fields.
+    #[expect(
+        clippy::cast_precision_loss,
+        clippy::cast_possible_truncation,
+        clippy::cast_sign_loss
+    )]
+    fn mine_probabilities(&self, mines: usize) -> BTreeMap<(usize, usize), f64> {
+        let constraints = self.constraints();
+        let mut probabilities = BTreeMap::new();
+        let mut distributions = Vec::new();
+        let mut estimated_mines = 0.0;
+
+        for group in Group::from_constraints(&constraints) {
+            if let Some(distribution) = Enumeration::run(&group) {
+                distributions.push((group, distribution));
+            } else {
+                for (index, field) in group.fields.iter().enumerate() {
+                    let density = group.density(index);
+                    estimated_mines += density;
+                    probabilities.insert(*field, density);
+                }
+            }
+        }
+
+        let mines = mines.saturating_sub(estimated_mines.round() as usize);
+        let rest: Vec<(usize, usize)> = self
+            .cells
+            .enumerate()
+            .filter(|(_, cell)| matches!(cell, Cell::Covered { .. }))
+            .map(|(coordinate, _)| coordinate.into())
+            .filter(|field| {
+                !probabilities.contains_key(field)
+                    && !distributions
+                        .iter()
+                        .any(|(group, _)| group.fields.binary_search(field).is_ok())
+            })
+            .collect();
+        let rest_weights = binomials(rest.len(), mines);
+        let mut prefixes = vec![vec![1.0]];
+
+        for (_, distribution) in &distributions {
+            let prefix = prefixes.last().map_or_else(Vec::new, |prefix| {
+                convolve(prefix, &distribution.weights, mines)
+            });
+            prefixes.push(prefix);
+        }
+
+        let mut suffixes = vec![vec![1.0]];
+
+        for (_, distribution) in distributions.iter().rev() {
+            let suffix = suffixes.last().map_or_else(Vec::new, |suffix| {
+                convolve(suffix, &distribution.weights, mines)
+            });
+            suffixes.push(suffix);
+        }
+
+        suffixes.reverse();
+
+        for (index, (group, distribution)) in distributions.iter().enumerate() {
+            let others = convolve(&prefixes[index], &suffixes[index + 1], mines);
+            let weights: Vec<f64> = (0..distribution.weights.len())
+                .map(|group_mines| weight(&others, &rest_weights, mines, group_mines))
+                .collect();
+            let total: f64 = distribution
+                .weights
+                .iter()
+                .zip(&weights)
+                .map(|(solutions, weight)| solutions * weight)
+                .sum();
+
+            for (field, field_mines) in group.fields.iter().zip(&distribution.mines) {
+                let probability = if total > 0.0 {
+                    field_mines
+                        .iter()
+                        .zip(&weights)
+                        .map(|(solutions, weight)| solutions * weight)
+                        .sum::<f64>()
+                        / total
+                } else {
+                    0.0
+                };
+                probabilities.insert(*field, probability);
+            }
+        }
+
+        if !rest.is_empty() {
+            let frontier = prefixes.last().map_or(&[][..], Vec::as_slice);
+            let mut total = 0.0;
+            let mut expected = 0.0;
+
+            for (frontier_mines, solutions) in frontier.iter().enumerate() {
+                if let Some(weight) = mines
+                    .checked_sub(frontier_mines)
+                    .and_then(|rest_mines| rest_weights.get(rest_mines))
+                {
+                    total += solutions * weight;
+                    expected += solutions * weight * (mines - frontier_mines) as f64;
+                }
+            }
+
+            let probability = if total > 0.0 {
+                expected / total / rest.len() as f64
+            } else {
+                (mines as f64 / rest.len() as f64).min(1.0)
+            };
+
+            for field in rest {
+                probabilities.insert(field, probability);
+            }
+        }
+
+        probabilities
+    }
+}
+
+/// A group of constraints, which share covered fields.
+#[derive(Debug)]
+struct Group {
+    fields: Vec<(usize, usize)>,
+    constraints: Vec<(Vec<usize>, usize)>,
+}
+
+impl Group {
+    /// Split the given constraints into groups of constraints, which share covered fields.
+    fn from_constraints(constraints: &[Constraint]) -> Vec<Self> {
+        let mut index: BTreeMap<(usize, usize), Vec<usize>> = BTreeMap::new();
+
+        for (position, constraint) in constraints.iter().enumerate() {
+            for &field in constraint.fields() {
+                index.entry(field).or_default().push(position);
+            }
+        }
+
+        let mut grouped = vec![false; constraints.len()];
+        let mut groups = Vec::new();
+
+        for start in 0..constraints.len() {
+            if grouped[start] {
+                continue;
+            }
+
+            grouped[start] = true;
+            let mut members = vec![start];
+            let mut queue = vec![start];
+
+            while let Some(position) = queue.pop() {
+                for field in constraints[position].fields() {
+                    for &neighbor in index.get(field).into_iter().flatten() {
+                        if !grouped[neighbor] {
+                            grouped[neighbor] = true;
+                            members.push(neighbor);
+                            queue.push(neighbor);
+                        }
+                    }
+                }
+            }
+
+            let fields: Vec<_> = members
+                .iter()
+                .flat_map(|&member| constraints[member].fields().iter().copied())
+                .collect::<BTreeSet<_>>()
+                .into_iter()
+                .collect();
+            let constraints = members
+                .iter()
+                .map(|&member| {
+                    (
+                        constraints[member]
+                            .fields()
+                            .iter()
+                            .filter_map(|field| fields.binary_search(field).ok())
+                            .collect(),
+                        constraints[member].mines(),
+                    )
+                })
+                .collect();
+            groups.push(Self {
+                fields,
+                constraints,
+            });
+        }
+
+        groups
+    }
+
+    /// Return the highest mine density among the constraints on the field at the given index.
+    #[expect(clippy::cast_precision_loss)]
+    fn density(&self, index: usize) -> f64 {
+        self.constraints
+            .iter()
+            .filter(|(fields, _)| fields.contains(&index))
+            .map(|(fields, mines)| *mines as f64 / fields.len() as f64)
+            .fold(0.0, f64::max)
+    }
+}
+
+/// Weighted amounts of solutions of a group by the amount of mines within the group.
+#[derive(Debug)]
+struct Distribution {
+    weights: Vec<f64>,
+    mines: Vec<Vec<f64>>,
+}
+
+/// Enumeration of all solutions of a group of constraints.
+#[derive(Debug)]
+struct Enumeration<'group> {
+    group: &'group Group,
+    memberships: Vec<Vec<usize>>,
+    assigned: Vec<usize>,
+    open: Vec<usize>,
+    assignment: Vec<bool>,
+    steps: usize,
+    distribution: Distribution,
+}
+
+impl<'group> Enumeration<'group> {
+    /// Enumerate all solutions of the given group.
+    ///
+    /// Return `None` if the enumeration exceeds the budget of steps.
+    fn run(group: &'group Group) -> Option<Distribution> {
+        let size = group.fields.len();
+        let mut memberships = vec![Vec::new(); size];
+
+        for (position, (fields, _)) in group.constraints.iter().enumerate() {
+            for &field in fields {
+                memberships[field].push(position);
+            }
+        }
+
+        let mut enumeration = Self {
+            group,
+            memberships,
+            assigned: vec![0; group.constraints.len()],
+            open: group
+                .constraints
+                .iter()
+                .map(|(fields, _)| fields.len())
+                .collect(),
+            assignment: vec![false; size],
+            steps: 0,
+            distribution: Distribution {
+                weights: vec![0.0; size + 1],
+                mines: vec![vec![0.0; size + 1]; size],
+            },
+        };
+
+        if !enumeration.search(0) {
+            return None;
+        }
+
+        let mut distribution = enumeration.distribution;
+        let max = distribution.weights.iter().copied().fold(0.0, f64::max);
+
+        if max > 0.0 {
+            distribution
+                .weights
+                .iter_mut()
+                .chain(distribution.mines.iter_mut().flatten())
+                .for_each(|weight| *weight /= max);
+        }
+
+        Some(distribution)
+    }
+
+    /// Assign the field at the given index and all following ones.
+    ///
+    /// Return `false` if the budget of steps has been exceeded.
+    fn search(&mut self, index: usize) -> bool {
+        self.steps += 1;
+
+        if self.steps > MAX_STEPS {
+            return false;
+        }
+
+        if index == self.group.fields.len() {
+            let mines = self.assignment.iter().filter(|&&mine| mine).count();
+            self.distribution.weights[mines] += 1.0;
+
+            for (field, _) in self
+                .assignment
+                .iter()
+                .enumerate()
+                .filter(|(_, mine)| **mine)
+            {
+                self.distribution.mines[field][mines] += 1.0;
+            }
+
+            return true;
+        }
+
+        for mine in [false, true] {
+            let feasible = self.assign(index, mine);
+            let completed = !feasible || self.search(index + 1);
+            self.unassign(index, mine);
+
+            if !completed {
+                return false;
+            }
+        }
+
+        true
+    }
+
+    /// Assign the field at the given index and return whether all constraints can still be met.
+    fn assign(&mut self, index: usize, mine: bool) -> bool {
+        self.assignment[index] = mine;
+        let mut feasible = true;
+
+        for &position in &self.memberships[index] {
+            self.open[position] -= 1;
+
+            if mine {
+                self.assigned[position] += 1;
+            }
+
+            let target = self.group.constraints[position].1;
+            feasible &= self.assigned[position] <= target
+                && self.assigned[position] + self.open[position] >= target;
+        }
+
+        feasible
+    }
+
+    /// Revert the assignment of the field at the given index.
+    fn unassign(&mut self, index: usize, mine: bool) {
+        self.assignment[index] = false;
+
+        for &position in &self.memberships[index] {
+            self.open[position] += 1;
+
+            if mine {
+                self.assigned[position] -= 1;
+            }
+        }
+    }
+}
+
+/// Return the weight of a group having the given amount of mines,
+/// given the weights of the other groups and of the remaining fields.
+fn weight(others: &[f64], rest_weights: &[f64], mines: usize, group_mines: usize) -> f64 {
+    others
+        .iter()
+        .enumerate()
+        .filter_map(|(other_mines, solutions)| {
+            mines
+                .checked_sub(group_mines + other_mines)
+                .and_then(|rest_mines| rest_weights.get(rest_mines))
+                .map(|weight| solutions * weight)
+        })
+        .sum()
+}
+
+/// Convolve two distributions of mines, up to the given maximum amount of mines.
+///
+/// The result is normalized to prevent an overflow.
+fn convolve(lhs: &[f64], rhs: &[f64], max: usize) -> Vec<f64> {
+    let len = (lhs.len() + rhs.len()).saturating_sub(1).min(max + 1);
+    let mut result = vec![0.0; len];
+
+    for (i, a) in lhs.iter().enumerate() {
+        for (j, b) in rhs.iter().enumerate() {
+            if let Some(target) = result.get_mut(i + j) {
+                *target += a * b;
+            }
+        }
+    }
+
+    let max = result.iter().copied().fold(0.0, f64::max);
+
+    if max > 0.0 {
+        for weight in &mut result {
+            *weight /= max;
+        }
+    }
+
+    result
+}
+
+/// Return the normalized binomial coefficients of choosing `0..=max` out of `n` items.
+#[expect(clippy::cast_precision_loss)]
+fn binomials(n: usize, max: usize) -> Vec<f64> {
+    let mut ln_factorials = Vec::with_capacity(n + 1);
+    let mut ln_factorial = 0.0;
+    ln_factorials.push(ln_factorial);
+
+    for i in 1..=n {
+        ln_factorial += (i as f64).ln();
+        ln_factorials.push(ln_factorial);
+    }
+
+    let ln_binomials: Vec<Option<f64>> = (0..=max)
+        .map(|k| {
+            n.checked_sub(k)
+                .map(|rest| ln_factorials[n] - ln_factorials[k] - ln_factorials[rest])
+        })
+        .collect();
+    let ln_max = ln_binomials
+        .iter()
+        .flatten()
+        .copied()
+        .fold(f64::NEG_INFINITY, f64::max);
+    ln_binomials
+        .into_iter()
+        .map(|ln_binomial| ln_binomial.map_or(0.0, |ln_binomial| (ln_binomial - ln_max).exp()))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::solver::Solver;
+    use crate::solver::testing::cases;
+
+    #[test]
+    #[expect(clippy::cast_precision_loss)]
+    fn probabilities_match_brute_force() {
+        for case in cases(200) {
+            let solutions = case.solutions();
+            let probabilities = Solver::new(&case.views).probabilities(case.mines.len(), 0);
+
+            for (coordinate, probability) in probabilities.enumerate() {
+                let expected = solutions
+                    .iter()
+                    .filter(|mines| mines.contains(&coordinate))
+                    .count() as f64
+                    / solutions.len() as f64;
+                assert!(
+                    (probability - expected).abs() < 1e-9,
+                    "{probability} != {expected} at {coordinate:?} of\n{:?}",
+                    case.views
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn duds_lower_probabilities() {
+        for case in cases(20) {
+            let mines = case.mines.len();
+            let solver = Solver::new(&case.views);
+            let live = solver.probabilities(mines, 0);
+            let with_duds = solver.probabilities(mines, 1);
+
+            for ((_, live), (_, with_duds)) in live.enumerate().zip(with_duds.enumerate()) {
+                #[expect(clippy::cast_precision_loss)]
+                let expected = live * (mines - 1) as f64 / mines as f64;
+                assert!((with_duds - expected).abs() < 1e-9);
+            }
+        }
+    }
+}
@@ -0,0 +1,121 @@
+//! Small, partially revealed boards to check the solver against brute-force enumeration.
+
+use std::num::NonZero;
+
+use grid2d::{Coordinate, Grid};
+
+use crate::{Neighborhood, Topology, View};
+
+const WIDTH: NonZero<usize> = NonZero::new(5).expect("Width should be non-zero.");
+const HEIGHT: NonZero<usize> = NonZero::new(5).expect("Height should be non-zero.");
+
+/// Maximum amount of covered fields, so that all placements of mines can be enumerated.
+const MAX_COVERED: usize = 16;
+
+/// A board with its mines and the views of its fields, of which some cleared ones are revealed.
+pub struct Case {
+    pub mines: Vec<Coordinate>,
+    pub views: Grid<View>,
+}
+
+impl Case {
+    /// Return all placements of the board's amount of mines among its covered fields,
+    /// which match the revealed fields.
+    pub fn solutions(&self) -> Vec<Vec<Coordinate>> {
+        let covered: Vec<Coordinate> = self
+            .views
+            .enumerate()
+            .filter(|(_, view)| **view == View::Covered)
+            .map(|(coordinate, _)| coordinate)
+            .collect();
+        let amount = u32::try_from(self.mines.len()).expect("Amount of mines should fit.");
+
+        (0..1_u32 << covered.len())
+            .filter(|placement| placement.count_ones() == amount)
+            .map(|placement| {
+                covered
+                    .iter()
+                    .enumerate()
+                    .filter(|(index, _)| placement & (1 << index) != 0)
+                    .map(|(_, &coordinate)| coordinate)
+                    .collect::<Vec<_>>()
+            })
+            .filter(|mines| self.matches(mines))
+            .collect()
+    }
+
+    /// Return `true` if the given placement of mines matches the revealed fields.
+    fn matches(&self, mines: &[Coordinate]) -> bool {
+        self.views.enumerate().all(|(coordinate, view)| match view {
+            View::Clear { adjacent_mines } => {
+                adjacent(mines, coordinate) == usize::from(*adjacent_mines)
+            }
+            _ => true,
+        })
+    }
+}
+
+/// Return an iterator over the given amount of random boards.
+pub fn cases(amount: usize) -> impl Iterator<Item = Case> {
+    let mut random = Lcg(0x5EED);
+    (0..amount).map(move |_| random.case())
+}
+
+/// Return the amount of the given mines adjacent to the given coordinate.
+fn adjacent(mines: &[Coordinate], coordinate: Coordinate) -> usize {
+    Topology::Flat
+        .neighbors(Neighborhood::Moore, WIDTH, HEIGHT, coordinate)
+        .into_iter()
+        .filter(|neighbor| mines.contains(neighbor))
+        .count()
+}
+
+/// Linear congruential generator, which keeps the boards stable across releases of `rand`.
+struct Lcg(u64);
+
+impl Lcg {
+    /// Return a random number below the given bound.
+    fn below(&mut self, bound: usize) -> usize {
+        self.0 = self
+            .0
+            .wrapping_mul(6_364_136_223_846_793_005)
+            .wrapping_add(1_442_695_040_888_963_407);
+        ((self.0 >> 33) % bound as u64) as usize
+    }
+
+    /// Shuffle the given coordinates.
+    fn shuffle(&mut self, coordinates: &mut [Coordinate]) {
+        for index in (1..coordinates.len()).rev() {
+            coordinates.swap(index, self.below(index + 1));
+        }
+    }
+
+    /// Return a random board with three to eight mines and enough fields revealed,
+    /// so that at most [`MAX_COVERED`] fields remain covered.
+    fn case(&mut self) -> Case {
+        let mut coordinates: Vec<Coordinate> = Grid::<View>::new_default(WIDTH, HEIGHT)
+            .enumerate()
+            .map(|(coordinate, _)| coordinate)
+            .collect();
+        self.shuffle(&mut coordinates);
+        let amount = 3 + self.below(6);
+        let (mines, safe) = coordinates.split_at(amount);
+        let minimum = (WIDTH.get() * HEIGHT.get()).saturating_sub(MAX_COVERED);
+        let revealed = minimum + self.below(safe.len() - minimum + 1);
+        let mut views = Grid::new_default(WIDTH, HEIGHT);
+
+        for &coordinate in &safe[..revealed] {
+            if let Some(view) = views.get_mut(coordinate) {
+                *view = View::Clear {
+                    adjacent_mines: u16::try_from(adjacent(mines, coordinate))
+                        .expect("Amount of adjacent mines should fit."),
+                };
+            }
+        }
+
+        Case {
+            mines: mines.to_vec(),
+            views,
+        }
+    }
+}
@@ -9,12 +9,14 @@ pub enum Error {
     /// There is no game for the current player.
     NotPlaying,
     /// An error occurred when constructing the game board.
-    BoardError(rustymines::Error),
+    Board(rustymines::Error),
+    /// A board without guessing was requested with more than the given amount of fields.
+    NoGuessBoardTooLarge(usize),
 }
 
 impl From<rustymines::Error> for Error {
     fn from(error: rustymines::Error) -> Self {
-        Self::BoardError(error)
+        Self::Board(error)
     }
 }
 
@@ -26,11 +28,18 @@ impl<'r, 'o: 'r> Responder<'r, 'o> for Error {
                 .streamed_body(Cursor::new("You currently have no running game."))
                 .status(Status::NotFound)
                 .ok(),
-            Self::BoardError(error) => Response::build()
+            Self::Board(error) => Response::build()
                 .header(ContentType::HTML)
                 .streamed_body(Cursor::new(error.to_string()))
                 .status(Status::BadRequest)
                 .ok(),
+            Self::NoGuessBoardTooLarge(fields) => Response::build()
+                .header(ContentType::HTML)
+                .streamed_body(Cursor::new(format!(
+                    "Boards without guessing may have at most {fields} fields."
+                )))
+                .status(Status::BadRequest)
+                .ok(),
         }
     }
 }
@@ -1,5 +1,6 @@
+use std::borrow::Cow;
 use std::net::IpAddr;
-use std::sync::PoisonError;
+use std::sync::{Arc, Mutex, PoisonError};
 
 use rocket::info;
 use rustymines::{Action, Game, State};
@@ -15,9 +16,20 @@ pub trait GamesUtil {
     /// Start a new game for the given client.
     fn new_game(&self, client_addr: IpAddr, game: Game) -> View;
 
+    /// Apply the given function to the game of the given client.
+    ///
+    /// Each game has its own lock, so that a long-running move, such as generating a board
+    /// without guessing, does not block the other players.
+    fn with_game<F>(&self, client_addr: &IpAddr, f: F) -> Result<View, Error>
+    where
+        F: FnOnce(&mut GameState) -> View;
+
     /// Toggle between flagging mode or visiting fields for the given game.
     fn toggle_flag(&self, client_addr: &IpAddr) -> Result<View, Error>;
 
+    /// Pause or resume the given game.
+    fn toggle_pause(&self, client_addr: &IpAddr) -> Result<View, Error>;
+
     /// Perform a user action.
     fn make_move(&self, client_addr: &IpAddr, action: Action) -> Result<View, Error>;
 
@@ -31,38 +43,57 @@ impl GamesUtil for Games {
         let view = WebUi::new(&game_state, None).into();
         self.write()
             .unwrap_or_else(PoisonError::into_inner)
-            .insert(client_addr, game_state);
+            .insert(client_addr, Arc::new(Mutex::new(game_state)));
         info!("Current games: {:?}", self.games());
         view
     }
 
-    fn toggle_flag(&self, client_addr: &IpAddr) -> Result<View, Error> {
-        self.write()
+    fn with_game<F>(&self, client_addr: &IpAddr, f: F) -> Result<View, Error>
+    where
+        F: FnOnce(&mut GameState) -> View,
+    {
+        let game = self
+            .read()
             .unwrap_or_else(PoisonError::into_inner)
-            .get_mut(client_addr)
-            .ok_or(Error::NotPlaying)
-            .map(|wrapper| {
-                wrapper.toggle_flag();
-                WebUi::new(wrapper, None).into()
-            })
+            .get(client_addr)
+            .cloned()
+            .ok_or(Error::NotPlaying)?;
+        Ok(f(&mut game.lock().unwrap_or_else(PoisonError::into_inner)))
+    }
+
+    fn toggle_flag(&self, client_addr: &IpAddr) -> Result<View, Error> {
+        self.with_game(client_addr, |wrapper| {
+            wrapper.toggle_flag();
+            WebUi::new(wrapper, None).into()
+        })
+    }
+
+    fn toggle_pause(&self, client_addr: &IpAddr) -> Result<View, Error> {
+        self.with_game(client_addr, |wrapper| {
+            if !wrapper.resume() {
+                wrapper.pause();
+            }
+
+            WebUi::new(wrapper, None).into()
+        })
     }
 
     fn make_move(&self, client_addr: &IpAddr, action: Action) -> Result<View, Error> {
-        self.write()
-            .unwrap_or_else(PoisonError::into_inner)
-            .get_mut(client_addr)
-            .ok_or(Error::NotPlaying)
-            .map(|wrapper| {
-                let Some(state) = wrapper.next_round(action) else {
-                    return WebUi::new(wrapper, None).into();
-                };
-
-                match state {
-                    State::Continue | State::GameOver(_) => WebUi::new(wrapper, None),
-                    State::InvalidMove => WebUi::new(wrapper, Some("Invalid coordinate.")),
-                }
-                .into()
-            })
+        self.with_game(client_addr, |wrapper| {
+            let Some(state) = wrapper.next_round(action) else {
+                return WebUi::new(wrapper, None).into();
+            };
+
+            let message = match state {
+                State::Continue | State::GameOver(_) => None,
+                State::InvalidMove => Some(Cow::Borrowed("Invalid coordinate.")),
+                State::Paused => Some(Cow::Borrowed("The game is paused.")),
+                State::DudTriggered(_) => Some(Cow::Borrowed("Phew! That mine was a dud.")),
+                State::LifeLost(_) => Some(Cow::Borrowed("Boom! That mine cost you a life.")),
+                State::Error(error) => Some(Cow::Owned(format!("Error: {error}"))),
+            };
+            WebUi::new(wrapper, message.as_deref()).into()
+        })
     }
 
     fn games(&self) -> Vec<IpAddr> {
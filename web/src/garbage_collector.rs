@@ -1,5 +1,5 @@
 use std::sync::atomic::{AtomicBool, Ordering};
-use std::sync::{Arc, PoisonError};
+use std::sync::{Arc, PoisonError, TryLockError};
 use std::thread::{JoinHandle, sleep, spawn};
 use std::time::Duration;
 
@@ -37,12 +37,20 @@ impl GarbageCollector {
     }
 
     /// Remove finished or timed-out games.
+    ///
+    /// Games which are currently locked, e.g. while generating their board, are kept.
     fn collect(&self) {
         self.games
             .write()
             .unwrap_or_else(PoisonError::into_inner)
             .retain(|key, wrapper| {
-                if wrapper.duration() > MAX_GAME_DURATION {
+                let wrapper = match wrapper.try_lock() {
+                    Ok(wrapper) => wrapper,
+                    Err(TryLockError::Poisoned(error)) => error.into_inner(),
+                    Err(TryLockError::WouldBlock) => return true,
+                };
+
+                if wrapper.start().elapsed() > MAX_GAME_DURATION {
                     info!("Dropping game: {key}");
                     false
                 } else {
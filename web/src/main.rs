@@ -3,7 +3,7 @@
 use std::collections::BTreeMap;
 use std::net::IpAddr;
 use std::sync::atomic::AtomicBool;
-use std::sync::{Arc, RwLock};
+use std::sync::{Arc, Mutex, RwLock};
 
 use rocket::{Build, Rocket, launch, routes};
 
@@ -16,11 +16,12 @@ mod games_util;
 mod garbage_collector;
 mod make_move;
 mod new_game;
+mod pause;
 mod toggle_mode;
 mod view;
 mod web_ui;
 
-type Games = Arc<RwLock<BTreeMap<IpAddr, GameState>>>;
+type Games = Arc<RwLock<BTreeMap<IpAddr, Arc<Mutex<GameState>>>>>;
 const TITLE: &str = "RustyMines";
 const FONT_SIZE: &str = "2em";
 
@@ -38,6 +39,7 @@ fn rocket() -> Rocket<Build> {
             new_game::create_custom,
             make_move::make_move,
             toggle_mode::toggle_mode,
+            pause::pause,
         ],
     )
 }
@@ -9,6 +9,7 @@ use crate::error::Error;
 use crate::games_util::GamesUtil;
 use crate::view::View;
 
+mod kind;
 mod request;
 
 #[post("/", format = "application/x-www-form-urlencoded", data = "<request>")]
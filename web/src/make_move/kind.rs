@@ -0,0 +1,17 @@
+use rocket::FromFormField;
+
+/// Kinds of moves that can be made on a field.
+#[derive(Clone, Copy, Debug, Default, Eq, FromFormField, PartialEq)]
+pub enum Kind {
+    /// Visit the field.
+    #[default]
+    Visit,
+    /// Toggle the flag on the field.
+    Flag,
+    /// Put another flag onto the field.
+    Raise,
+    /// Toggle the question mark on the field.
+    Mark,
+    /// Visit the non-flagged neighbors of the field.
+    Chord,
+}
@@ -2,20 +2,25 @@ use rocket::FromForm;
 use rustymines::Action;
 use rustymines::grid::Coordinate;
 
+use super::kind::Kind;
+
 /// Request for a new game.
 #[derive(Clone, Debug, FromForm, Eq, PartialEq)]
 pub struct Request {
     x: usize,
     y: usize,
-    flag: bool,
+    #[field(default = Kind::Visit)]
+    kind: Kind,
 }
 
 impl From<Request> for Action {
     fn from(request: Request) -> Self {
-        if request.flag {
-            Self::ToggleFlag(request.into())
-        } else {
-            Self::Visit(request.into())
+        match request.kind {
+            Kind::Visit => Self::Visit(request.into()),
+            Kind::Flag => Self::ToggleFlag(request.into()),
+            Kind::Raise => Self::RaiseFlag(request.into()),
+            Kind::Mark => Self::ToggleMark(request.into()),
+            Kind::Chord => Self::Chord(request.into()),
         }
     }
 }
@@ -1,6 +1,7 @@
 use build_html::{Container, ContainerType, HtmlContainer, HtmlPage};
 use rocket::form::Form;
 use rocket::{State, get, post};
+use rustymines::{Config, Difficulty, Game};
 
 use self::request::Request;
 use crate::error::Error;
@@ -8,12 +9,18 @@ use crate::games_util::GamesUtil;
 use crate::view::View;
 use crate::{FONT_SIZE, Games, IpAddr, TITLE};
 
+mod neighborhood;
 mod request;
 
+const PRESETS: [(&str, Difficulty); 3] = [
+    ("Beginner", Difficulty::Beginner),
+    ("Intermediate", Difficulty::Intermediate),
+    ("Expert", Difficulty::Expert),
+];
+
 #[get("/")]
 pub fn default(games: &State<Games>, client_addr: IpAddr) -> Result<View, Error> {
-    Request::default()
-        .try_into()
+    Game::from_difficulty(Difficulty::default())
         .map(|game| games.new_game(client_addr, game))
         .map_err(Into::into)
 }
@@ -37,15 +44,15 @@ pub fn create_custom(
         .into_inner()
         .try_into()
         .map(|game| games.new_game(client_addr, game))
-        .map_err(Into::into)
 }
 
 fn custom_game_config_page() -> View {
+    let max = Config::MAX_DIMENSION;
     let width = format!(
-        r#"<input type="number" name="width" placeholder="width" style="font-size: {FONT_SIZE};">"#
+        r#"<input type="number" name="width" placeholder="width" min="1" max="{max}" style="font-size: {FONT_SIZE};">"#
     );
     let height = format!(
-        r#"<input type="number" name="height" placeholder="height" style="font-size: {FONT_SIZE};">"#
+        r#"<input type="number" name="height" placeholder="height" min="1" max="{max}" style="font-size: {FONT_SIZE};">"#
     );
     let mines = format!(
         r#"<input type="number" name="mines" placeholder="mines" style="font-size: {FONT_SIZE};">"#
@@ -53,10 +60,32 @@ fn custom_game_config_page() -> View {
     let duds = format!(
         r#"<input type="number" name="duds" placeholder="duds" style="font-size: {FONT_SIZE};">"#
     );
+    let mines_per_field = format!(
+        r#"<input type="number" name="mines_per_field" placeholder="mines per field" style="font-size: {FONT_SIZE};">"#
+    );
+    let no_guess = format!(
+        r#"<label style="font-size: {FONT_SIZE};"><input type="checkbox" name="no_guess" style="font-size: {FONT_SIZE};">No guessing</label>"#
+    );
+    let torus = format!(
+        r#"<label style="font-size: {FONT_SIZE};"><input type="checkbox" name="torus" style="font-size: {FONT_SIZE};">Wrap around edges</label>"#
+    );
+    let neighborhood = format!(
+        r#"<select name="neighborhood" style="font-size: {FONT_SIZE};"><option value="moore">Square fields</option><option value="hexagonal">Hexagonal fields</option><option value="cross">Orthogonal neighbors</option><option value="knight">Knight's-move neighbors</option><option value="radius-2">Neighbors within two fields</option></select>"#
+    );
+    let time_limit = format!(
+        r#"<input type="number" name="time_limit" placeholder="time limit (s)" style="font-size: {FONT_SIZE};">"#
+    );
+    let move_limit = format!(
+        r#"<input type="number" name="move_limit" placeholder="move limit" style="font-size: {FONT_SIZE};">"#
+    );
+    let lives = format!(
+        r#"<input type="number" name="lives" placeholder="lives" style="font-size: {FONT_SIZE};">"#
+    );
     let button = format!(r#"<input type="submit" value="Start" style="font-size: {FONT_SIZE};">"#);
     let form = format!(
-        r#"<form action="/custom" method="post">{width}<br/>{height}<br/>{mines}<br/>{duds}<br/>{button}</form>"#
+        r#"<form action="/custom" method="post">{width}<br/>{height}<br/>{mines}<br/>{duds}<br/>{mines_per_field}<br/>{time_limit}<br/>{move_limit}<br/>{lives}<br/>{no_guess}<br/>{torus}<br/>{neighborhood}<br/>{button}</form>"#
     );
+    let presets: String = PRESETS.into_iter().map(preset_form).collect();
     HtmlPage::new()
         .with_title(TITLE)
         .with_container(
@@ -66,7 +95,17 @@ fn custom_game_config_page() -> View {
                     ("justify-content", "center"),
                     ("align", "center"),
                 ])
+                .with_raw(presets)
                 .with_raw(form),
         )
         .into()
 }
+
+fn preset_form((name, difficulty): (&str, Difficulty)) -> String {
+    let width = difficulty.width();
+    let height = difficulty.height();
+    let mines = difficulty.mines();
+    format!(
+        r#"<form action="/custom" method="post"><input type="hidden" name="width" value="{width}"><input type="hidden" name="height" value="{height}"><input type="hidden" name="mines" value="{mines}"><input type="submit" value="{name}" style="font-size: {FONT_SIZE};"></form>"#
+    )
+}
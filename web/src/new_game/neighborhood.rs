@@ -0,0 +1,34 @@
+use std::num::NonZero;
+
+use rocket::FromFormField;
+
+const TWO: NonZero<u8> = NonZero::new(2).expect("Two should be non-zero.");
+
+/// Neighborhoods of fields selectable for a new game.
+#[derive(Clone, Copy, Debug, Default, Eq, FromFormField, PartialEq)]
+pub enum Neighborhood {
+    /// Square fields with eight neighbors.
+    #[default]
+    Moore,
+    /// Hexagonal fields with six neighbors.
+    Hexagonal,
+    /// Square fields with their four orthogonal neighbors.
+    Cross,
+    /// Square fields with the eight fields a knight can move to in chess as neighbors.
+    Knight,
+    /// Square fields with all fields within a distance of two as neighbors.
+    #[field(value = "radius-2")]
+    Radius2,
+}
+
+impl From<Neighborhood> for rustymines::Neighborhood {
+    fn from(neighborhood: Neighborhood) -> Self {
+        match neighborhood {
+            Neighborhood::Moore => Self::Moore,
+            Neighborhood::Hexagonal => Self::Hexagonal,
+            Neighborhood::Cross => Self::VonNeumann,
+            Neighborhood::Knight => Self::Knight,
+            Neighborhood::Radius2 => Self::Radius(TWO),
+        }
+    }
+}
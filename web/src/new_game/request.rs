@@ -1,45 +1,83 @@
 use std::num::NonZero;
+use std::time::Duration;
 
 use rocket::FromForm;
-use rustymines::Game;
+use rustymines::{Config, Difficulty, Game, Topology};
 
-const DEFAULT_SIZE: NonZero<u8> = NonZero::new(8).expect("Default size should be non-zero.");
-const DEFAULT_MINES: u8 = 10;
-const DEFAULT_DUDS: u8 = 0;
+use crate::error::Error;
+use crate::new_game::neighborhood::Neighborhood;
+
+const DEFAULT_DUDS: usize = 0;
+const NO_GUESS_ATTEMPTS: NonZero<usize> =
+    NonZero::new(100).expect("No-guess attempts should be non-zero.");
+const NO_GUESS_MAX_FIELDS: usize =
+    Difficulty::Expert.width().get() * Difficulty::Expert.height().get();
 
 /// Request for a new game.
+///
+/// Missing board dimensions and amount of mines default to the beginner difficulty.
+/// Boards without guessing are limited to the size of the expert difficulty,
+/// to bound the time spent generating them.
 #[derive(Clone, Debug, FromForm, Eq, PartialEq)]
 pub struct Request {
-    #[field(default = DEFAULT_SIZE)]
-    width: NonZero<u8>,
-    #[field(default = DEFAULT_SIZE)]
-    height: NonZero<u8>,
-    #[field(default = DEFAULT_MINES)]
-    mines: u8,
+    width: Option<NonZero<usize>>,
+    height: Option<NonZero<usize>>,
+    mines: Option<usize>,
     #[field(default = DEFAULT_DUDS)]
-    duds: u8,
-}
-
-impl Default for Request {
-    fn default() -> Self {
-        Self {
-            width: DEFAULT_SIZE,
-            height: DEFAULT_SIZE,
-            mines: DEFAULT_MINES,
-            duds: DEFAULT_DUDS,
-        }
-    }
+    duds: usize,
+    mines_per_field: Option<NonZero<u8>>,
+    #[field(default = false)]
+    no_guess: bool,
+    #[field(default = false)]
+    torus: bool,
+    #[field(default = Neighborhood::Moore)]
+    neighborhood: Neighborhood,
+    time_limit: Option<NonZero<u64>>,
+    move_limit: Option<NonZero<usize>>,
+    lives: Option<NonZero<usize>>,
 }
 
 impl TryFrom<Request> for Game {
-    type Error = rustymines::Error;
+    type Error = Error;
 
     fn try_from(request: Request) -> Result<Self, Self::Error> {
-        Self::new(
-            request.width.into(),
-            request.height.into(),
-            request.mines,
-            request.duds,
+        let difficulty = Difficulty::default();
+        let config = Config::new(
+            request.width.unwrap_or_else(|| difficulty.width()),
+            request.height.unwrap_or_else(|| difficulty.height()),
+            request.mines.unwrap_or_else(|| difficulty.mines()),
         )
+        .with_duds(request.duds)
+        .with_topology(if request.torus {
+            Topology::Torus
+        } else {
+            Topology::Flat
+        })
+        .with_neighborhood(request.neighborhood.into());
+        let config = request.time_limit.map_or(config, |seconds| {
+            config.with_time_limit(Duration::from_secs(seconds.get()))
+        });
+        let config = request
+            .move_limit
+            .map_or(config, |moves| config.with_move_limit(moves));
+        let config = request
+            .mines_per_field
+            .map_or(config, |mines| config.with_mines_per_field(mines));
+        let config = request
+            .lives
+            .map_or(config, |lives| config.with_lives(lives));
+
+        if !request.no_guess {
+            return config.try_into().map_err(Into::into);
+        }
+
+        if config.width().get().saturating_mul(config.height().get()) > NO_GUESS_MAX_FIELDS {
+            return Err(Error::NoGuessBoardTooLarge(NO_GUESS_MAX_FIELDS));
+        }
+
+        config
+            .with_no_guess(NO_GUESS_ATTEMPTS)
+            .try_into()
+            .map_err(Into::into)
     }
 }
@@ -0,0 +1,13 @@
+use std::net::IpAddr;
+
+use rocket::post;
+
+use crate::Games;
+use crate::error::Error;
+use crate::games_util::GamesUtil;
+use crate::view::View;
+
+#[post("/pause", format = "application/x-www-form-urlencoded")]
+pub fn pause(games: &rocket::State<Games>, client_addr: IpAddr) -> Result<View, Error> {
+    games.toggle_pause(&client_addr)
+}
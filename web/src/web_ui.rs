@@ -4,7 +4,7 @@ use build_html::{
     Container, ContainerType, Html, HtmlContainer, HtmlElement, HtmlPage, HtmlTag, Table,
     TableCell, TableCellType, TableRow,
 };
-use rustymines::Outcome;
+use rustymines::{Outcome, View};
 
 use crate::game_state::GameState;
 use crate::{FONT_SIZE, TITLE};
@@ -44,23 +44,50 @@ impl WebUi<'_, '_> {
 
     fn grid(&self) -> Table {
         let mut grid = Table::new().with_attributes([("style", "margin: 0 auto;")]);
+        let hexagonal = self.game_state.config().neighborhood().is_hexagonal();
+        let mines_per_field = self.game_state.config().mines_per_field().get();
 
         for (y, fields) in self.game_state.rows().enumerate() {
-            let mut row = TableRow::new();
+            // Shift odd rows of hexagonal fields by half a field.
+            let mut row = if hexagonal && y % 2 == 1 {
+                TableRow::new().with_attributes([(
+                    "style",
+                    format!("position: relative; left: calc({BUTTON_SIZE} / 2);").as_str(),
+                )])
+            } else {
+                TableRow::new()
+            };
 
             for (x, view) in fields.enumerate() {
                 let mut cell = TableCell::new(TableCellType::Data);
                 let x_input = format!(r#"<input type="hidden" name="x" value="{x}">"#);
                 let y_input = format!(r#"<input type="hidden" name="y" value="{y}">"#);
-                let flag = format!(
-                    r#"<input type="hidden" name="flag" value="{}">"#,
-                    self.game_state.flag()
-                );
+                let flags = match view {
+                    View::Flag => 1,
+                    View::Flags(flags) => flags,
+                    _ => 0,
+                };
+                // Add flags up to the maximum amount of mines per field,
+                // then replace them with a question mark and finally remove it.
+                let kind = if !self.game_state.flag() {
+                    if matches!(view, View::Clear { adjacent_mines } if adjacent_mines > 0) {
+                        "chord"
+                    } else {
+                        "visit"
+                    }
+                } else if view == View::Questioned || flags >= mines_per_field {
+                    "mark"
+                } else if flags > 0 {
+                    "raise"
+                } else {
+                    "flag"
+                };
+                let kind = format!(r#"<input type="hidden" name="kind" value="{kind}">"#);
                 let button = format!(
                     r#"<input type="submit" value="{view}" style="width: {BUTTON_SIZE}; height: {BUTTON_SIZE}; font-size: {FONT_SIZE};">"#,
                 );
                 let form = format!(
-                    r#"<form action="/" method="post">{button}{x_input}{y_input}{flag}</form>"#
+                    r#"<form action="/" method="post">{button}{x_input}{y_input}{kind}</form>"#
                 );
                 cell.add_raw(&form);
                 row.add_cell(cell);
@@ -81,6 +108,14 @@ impl WebUi<'_, '_> {
                 "visit"
             }
         );
+        let pause_button = format!(
+            r#"<form action="/pause" method="post"><input type="submit" value="{}" style="font-size: {FONT_SIZE};"></form>"#,
+            if self.game_state.is_paused() {
+                "Resume"
+            } else {
+                "Pause"
+            }
+        );
         let new_game_button = format!(
             r#"<form action="/" method="get"><input type="submit" value="New game" style="font-size: {FONT_SIZE};"></form>"#,
         );
@@ -90,26 +125,63 @@ impl WebUi<'_, '_> {
         let mut container = Container::new(ContainerType::Footer)
             .with_raw(mode_button)
             .with_html(HtmlElement::new(HtmlTag::LineBreak))
+            .with_raw(pause_button)
+            .with_html(HtmlElement::new(HtmlTag::LineBreak))
             .with_html(
                 HtmlElement::new(HtmlTag::ParagraphText)
                     .with_attribute("style", format!("font-size: {FONT_SIZE};"))
                     .with_raw(format!("Flags: {}", self.game_state.flags())),
-            )
-            .with_html(HtmlElement::new(HtmlTag::LineBreak))
-            .with_raw(new_game_button)
-            .with_html(HtmlElement::new(HtmlTag::LineBreak))
-            .with_raw(new_custom_game_button);
+            );
 
-        if let Some(outcome) = self.game_state.outcome() {
-            container.add_html(if let Outcome::Won(_) = outcome {
+        if self.game_state.config().lives().get() > 1 {
+            container.add_html(
                 HtmlElement::new(HtmlTag::ParagraphText)
-                    .with_attribute("style", format!("color: green; font-size: {FONT_SIZE};"))
-                    .with_raw("You won the game.")
-            } else {
+                    .with_attribute("style", format!("font-size: {FONT_SIZE};"))
+                    .with_raw(format!("Lives: {}", self.game_state.lives())),
+            );
+        }
+
+        if let Some(time) = self.game_state.remaining_time() {
+            container.add_html(
+                HtmlElement::new(HtmlTag::ParagraphText)
+                    .with_attribute("style", format!("font-size: {FONT_SIZE};"))
+                    .with_raw(format!("Time left: {}s", time.as_secs())),
+            );
+        }
+
+        if let Some(moves) = self.game_state.remaining_moves() {
+            container.add_html(
+                HtmlElement::new(HtmlTag::ParagraphText)
+                    .with_attribute("style", format!("font-size: {FONT_SIZE};"))
+                    .with_raw(format!("Moves left: {moves}")),
+            );
+        }
+
+        container.add_html(HtmlElement::new(HtmlTag::LineBreak));
+        container.add_raw(new_game_button);
+        container.add_html(HtmlElement::new(HtmlTag::LineBreak));
+        container.add_raw(new_custom_game_button);
+
+        if let Some(outcome) = self.game_state.outcome() {
+            let (color, message) = match outcome {
+                Outcome::Won(_) => ("green", "You won the game."),
+                Outcome::Lost(_) => ("red", "You lost the game."),
+                Outcome::TimedOut(_) => ("red", "You ran out of time."),
+                Outcome::OutOfMoves(_) => ("red", "You ran out of moves."),
+            };
+            container.add_html(
                 HtmlElement::new(HtmlTag::ParagraphText)
-                    .with_attribute("style", format!("color: red; font-size: {FONT_SIZE};"))
-                    .with_raw("You lost the game.")
-            });
+                    .with_attribute("style", format!("color: {color}; font-size: {FONT_SIZE};"))
+                    .with_raw(message),
+            );
+
+            if let Some(statistics) = self.game_state.statistics() {
+                container.add_html(
+                    HtmlElement::new(HtmlTag::ParagraphText)
+                        .with_attribute("style", format!("font-size: {FONT_SIZE};"))
+                        .with_raw(statistics.to_string().replace('\n', "<br/>")),
+                );
+            }
         } else if let Some(message) = self.message {
             container.add_html(
                 HtmlElement::new(HtmlTag::ParagraphText)